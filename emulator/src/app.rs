@@ -1,7 +1,7 @@
 use super::edisplay::EDisplay;
 use eframe::{egui, epi};
 use rugui::coordinates::bounding_box::BBox;
-use rugui::framebuffer::{Color, Framebuffer};
+use rugui::framebuffer::{buffer_size, Color, Framebuffer};
 use rugui::geometry::Drawable;
 
 pub struct DisplayEmulator {
@@ -47,8 +47,8 @@ impl<'a> epi::App for DisplayEmulator {
             ellipse_thickness,
         } = self;
 
-        let size = resolution.0 * (resolution.1 as f32 / 8.0).ceil() as i32;
-        let mut buffer = vec![0; size as usize];
+        let size = buffer_size(resolution.0 as usize, resolution.1 as usize);
+        let mut buffer = vec![0; size];
         let mut framebuffer = Framebuffer::new(resolution.0, resolution.1, &mut buffer).unwrap();
 
         egui::SidePanel::left("side_panel").show(ctx, |ui| {
@@ -62,7 +62,8 @@ impl<'a> epi::App for DisplayEmulator {
             rugui::geometry::Rect::new_filled(BBox::new((90, 0), (159, 31)), Color::White)
                 .draw(&mut framebuffer);
             ui.add(egui::Slider::new(progress, 1..=100).text("progress"));
-            rugui::widgets::ProgressBar::new(BBox::new((15, 5), (90, 15)), *progress, Color::Black)
+            let bbox = BBox::new((15, 5), (90, 15));
+            rugui::widgets::ProgressBar::new(bbox, *progress as u32, 100, Color::Black)
                 .draw(&mut framebuffer);
 
             ui.add(egui::Slider::new(radius, 0..=16).text("radius"));
@@ -1,24 +1,103 @@
 use crate::coordinates::cvec::*;
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum Axis {
     X,
     Y,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Placement of an object along an axis within a bigger one.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Alignment {
+    Start,
+    Center,
+    End,
+}
+
+/// Amounts for each edge of a bounding box, e.g. for padding.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug)]
+pub struct Edges {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl Edges {
+    pub const fn new(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Same amount for every edge.
+    pub const fn all(amount: i32) -> Self {
+        Self::new(amount, amount, amount, amount)
+    }
+
+    /// `x` for the left and right edges, `y` for the top and bottom ones.
+    pub const fn symmetric(x: i32, y: i32) -> Self {
+        Self::new(x, y, x, y)
+    }
+
+    /// Sum of the left and right, and of the top and bottom amounts.
+    pub const fn size(&self) -> Vec2 {
+        (self.left + self.right, self.top + self.bottom)
+    }
+}
+
+impl From<i32> for Edges {
+    fn from(amount: i32) -> Self {
+        Self::all(amount)
+    }
+}
+
+impl From<Vec2> for Edges {
+    fn from((x, y): Vec2) -> Self {
+        Self::symmetric(x, y)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub struct BBox {
     pub start: Vec2,
     pub end: Vec2,
 }
 
 impl BBox {
-    pub fn new(start: Vec2, end: Vec2) -> Self {
-        Self { start, end }
+    /// Both corners are included. Accepts `Point`s as well as `(x, y)` tuples.
+    pub fn new(start: impl Into<Point>, end: impl Into<Point>) -> Self {
+        Self {
+            start: start.into().into(),
+            end: end.into().into(),
+        }
+    }
+
+    /// Bounding box at the `start` point with the `size`.
+    pub fn from_relative(start: impl Into<Point>, size: impl Into<Size>) -> Self {
+        let start = start.into();
+
+        Self::new(start, start + size.into())
+    }
+
+    /// Bounding box with the `size` around the `center` point. If the box is an even
+    /// number of pixels wide or high, the extra pixel goes after the center, matching `center`.
+    pub fn centered(center: impl Into<Point>, size: impl Into<Size>) -> Self {
+        let size = size.into();
+        let start = center.into() - Size::new(size.width / 2, size.height / 2);
+
+        Self::from_relative(start, size)
+    }
+
+    pub fn origin(&self) -> Point {
+        self.start.into()
     }
 
-    pub fn from_relative(start: Vec2, delta: Vec2) -> Self {
-        Self::new(start, (start.0 + delta.0, start.1 + delta.1))
+    pub fn size(&self) -> Size {
+        Point::from(self.end) - Point::from(self.start)
     }
 
     /// Make bounding box bigger or smaller in one dimension.
@@ -26,12 +105,12 @@ impl BBox {
     pub fn transform(&self, axis: Axis, delta: i32) -> Self {
         match axis {
             Axis::X => Self::new(
-                (self.start.0 - delta, self.start.1),
-                (self.end.0 + delta, self.end.1),
+                (self.start.0 + delta, self.start.1),
+                (self.end.0 - delta, self.end.1),
             ),
             Axis::Y => Self::new(
-                (self.start.0, self.start.1 - delta),
-                (self.end.0, self.end.1 + delta),
+                (self.start.0, self.start.1 + delta),
+                (self.end.0, self.end.1 - delta),
             ),
         }
     }
@@ -40,11 +119,94 @@ impl BBox {
     /// Positive `delta` value makes bounding box smaller.
     pub fn transform_both(&self, delta: i32) -> Self {
         Self::new(
-            (self.start.0 - delta, self.start.1 - delta),
-            (self.end.0 + delta, self.end.1 + delta),
+            (self.start.0 + delta, self.start.1 + delta),
+            (self.end.0 - delta, self.end.1 - delta),
         )
     }
 
+    /// Move the edges inwards by their amounts.
+    pub fn inset(&self, edges: impl Into<Edges>) -> Self {
+        let edges = edges.into();
+
+        Self::new(
+            (self.start.0 + edges.left, self.start.1 + edges.top),
+            (self.end.0 - edges.right, self.end.1 - edges.bottom),
+        )
+    }
+
+    /// Move the edges outwards by their amounts.
+    pub fn outset(&self, edges: impl Into<Edges>) -> Self {
+        let edges = edges.into();
+
+        Self::new(
+            (self.start.0 - edges.left, self.start.1 - edges.top),
+            (self.end.0 + edges.right, self.end.1 + edges.bottom),
+        )
+    }
+
+    /// Swap the coordinates where `start` is after `end`.
+    pub fn normalize(&self) -> Self {
+        Self::new(
+            (self.start.0.min(self.end.0), self.start.1.min(self.end.1)),
+            (self.start.0.max(self.end.0), self.start.1.max(self.end.1)),
+        )
+    }
+
+    /// Is the point inside, including the edges?
+    pub fn contains(&self, point: impl Into<Point>) -> bool {
+        let Point { x, y } = point.into();
+        x >= self.start.0 && x <= self.end.0 && y >= self.start.1 && y <= self.end.1
+    }
+
+    /// Middle point, rounded towards `start`.
+    pub fn center(&self) -> Point {
+        Point::new(
+            self.start.0 + (self.end.0 - self.start.0) / 2,
+            self.start.1 + (self.end.1 - self.start.1) / 2,
+        )
+    }
+
+    /// Area covered by both bounding boxes, `None` if they don't overlap.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let start = (
+            self.start.0.max(other.start.0),
+            self.start.1.max(other.start.1),
+        );
+        let end = (self.end.0.min(other.end.0), self.end.1.min(other.end.1));
+
+        if start.0 > end.0 || start.1 > end.1 {
+            return None;
+        }
+
+        Some(Self::new(start, end))
+    }
+
+    /// Smallest bounding box which covers both.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(
+            (
+                self.start.0.min(other.start.0),
+                self.start.1.min(other.start.1),
+            ),
+            (self.end.0.max(other.end.0), self.end.1.max(other.end.1)),
+        )
+    }
+
+    /// Move the bounding box, keeping its size, to a place within the `parent`.
+    /// A bigger bounding box sticks out of the `parent`.
+    pub fn align_within(&self, parent: &Self, horizontal: Alignment, vertical: Alignment) -> Self {
+        let offset = |alignment, free: i32| match alignment {
+            Alignment::Start => 0,
+            Alignment::Center => free / 2,
+            Alignment::End => free,
+        };
+        let (width, height) = (self.end.0 - self.start.0, self.end.1 - self.start.1);
+        let x = parent.start.0 + offset(horizontal, parent.end.0 - parent.start.0 - width);
+        let y = parent.start.1 + offset(vertical, parent.end.1 - parent.start.1 - height);
+
+        Self::from_relative((x, y), (width, height))
+    }
+
     /// Split bounding box by provided axis
     pub fn split(&self, axis: Axis, at: i32) -> (Self, Self) {
         match axis {
@@ -59,6 +221,28 @@ impl BBox {
         }
     }
 
+    /// Split bounding box by provided axis into `N` parts of equal size.
+    /// Pixels which can't be shared equally go to the first parts. `N == 0` gives no parts.
+    pub fn split_equal<const N: usize>(&self, axis: Axis) -> [Self; N] {
+        let (start, end) = match axis {
+            Axis::X => (self.start.0, self.end.0),
+            Axis::Y => (self.start.1, self.end.1),
+        };
+        let length = end - start + 1;
+        let parts = N.max(1) as i32;
+        let (size, remainder) = (length / parts, length % parts);
+
+        core::array::from_fn(|index| {
+            let index = index as i32;
+            let from = start + size * index + index.min(remainder);
+            let to = from + size - 1 + (index < remainder) as i32;
+            match axis {
+                Axis::X => Self::new((from, self.start.1), (to, self.end.1)),
+                Axis::Y => Self::new((self.start.0, from), (self.end.0, to)),
+            }
+        })
+    }
+
     #[inline(always)]
     pub fn width(&self) -> usize {
         (self.end.0 - self.start.0) as usize
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::bounding_box::*;
+    use crate::coordinates::cvec::{Point, Size};
+
+    #[test]
+    fn test_transform() {
+        let bbox = BBox::new((10, 10), (20, 30));
+
+        assert_eq!(bbox.transform(Axis::X, 2), BBox::new((12, 10), (18, 30)));
+        assert_eq!(bbox.transform(Axis::Y, -1), BBox::new((10, 9), (20, 31)));
+        assert_eq!(bbox.transform_both(3), BBox::new((13, 13), (17, 27)));
+
+        let edges = Edges::new(1, 2, 3, 4);
+        assert_eq!(bbox.inset(edges), BBox::new((11, 12), (17, 26)));
+        assert_eq!(bbox.outset(edges), BBox::new((9, 8), (23, 34)));
+        assert_eq!(bbox.inset(edges).outset(edges), bbox);
+        assert_eq!(bbox.inset((1, 2)), BBox::new((11, 12), (19, 28)));
+        assert_eq!(bbox.outset(1), BBox::new((9, 9), (21, 31)));
+    }
+
+    #[test]
+    fn test_overlap() {
+        let a = BBox::new((0, 0), (9, 9));
+        let b = BBox::new((5, 7), (14, 20));
+
+        assert_eq!(a.intersect(&b), Some(BBox::new((5, 7), (9, 9))));
+        assert_eq!(a.union(&b), BBox::new((0, 0), (14, 20)));
+
+        // Touching edges overlap by a line of pixels
+        let c = BBox::new((9, 0), (12, 3));
+        assert_eq!(a.intersect(&c), Some(BBox::new((9, 0), (9, 3))));
+        assert_eq!(a.intersect(&BBox::new((10, 0), (12, 3))), None);
+
+        assert!(a.contains((0, 0)));
+        assert!(a.contains((9, 9)));
+        assert!(!a.contains((10, 5)));
+        assert!(!a.contains((5, -1)));
+
+        assert_eq!(a.center(), Point::new(4, 4));
+        assert_eq!(b.center(), Point::new(9, 13));
+
+        let flipped = BBox::new((9, 3), (2, 7));
+        assert_eq!(flipped.normalize(), BBox::new((2, 3), (9, 7)));
+        assert!(flipped.normalize().contains((5, 5)));
+    }
+
+    #[test]
+    fn test_align_within() {
+        let parent = BBox::new((10, 20), (59, 39));
+        let bbox = BBox::new((0, 0), (9, 4));
+
+        let start = bbox.align_within(&parent, Alignment::Start, Alignment::Start);
+        assert_eq!(start, BBox::new((10, 20), (19, 24)));
+        let center = bbox.align_within(&parent, Alignment::Center, Alignment::Center);
+        assert_eq!(center, BBox::new((30, 27), (39, 31)));
+        let end = bbox.align_within(&parent, Alignment::End, Alignment::Center);
+        assert_eq!(end, BBox::new((50, 27), (59, 31)));
+
+        // A bigger box is centered over the parent
+        let big = BBox::new((0, 0), (69, 4));
+        let center = big.align_within(&parent, Alignment::Center, Alignment::End);
+        assert_eq!(center, BBox::new((0, 35), (69, 39)));
+    }
+
+    #[test]
+    fn test_point_size() {
+        let bbox = BBox::from_relative(Point::new(10, 20), Size::new(5, 3));
+        assert_eq!(bbox, BBox::new((10, 20), (15, 23)));
+        assert_eq!(bbox, BBox::new(Point::new(10, 20), (15, 23)));
+        assert_eq!(bbox.origin(), Point::new(10, 20));
+        assert_eq!(bbox.size(), Size::new(5, 3));
+
+        let centered = BBox::centered(bbox.center(), bbox.size());
+        assert_eq!(centered, bbox);
+        assert_eq!(BBox::centered((0, 0), (4, 4)), BBox::new((-2, -2), (2, 2)));
+    }
+
+    #[test]
+    fn test_split_equal() {
+        let bbox = BBox::new((0, 0), (9, 3));
+
+        let [a, b, c] = bbox.split_equal(Axis::X);
+        assert_eq!(a, BBox::new((0, 0), (3, 3)));
+        assert_eq!(b, BBox::new((4, 0), (6, 3)));
+        assert_eq!(c, BBox::new((7, 0), (9, 3)));
+
+        let rows: [BBox; 2] = bbox.split_equal(Axis::Y);
+        assert_eq!(rows, [BBox::new((0, 0), (9, 1)), BBox::new((0, 2), (9, 3))]);
+        assert_eq!(bbox.split_equal::<0>(Axis::Y), []);
+
+        let (left, right) = bbox.split(Axis::X, 4);
+        assert_eq!((left, right), (a, BBox::new((4, 0), (9, 3))));
+    }
+}
@@ -1,3 +1,5 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
 /// A vector of coordinates.
 /// May represent coordinates of the point or size of rect.
 pub type Vec2 = (i32, i32);
@@ -19,3 +21,150 @@ impl Vector2 for Vec2 {
         (x / scalar, y / scalar)
     }
 }
+
+/// Position of a pixel.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Dimensions of an object, with the same convention as `BBox::width` and `BBox::height`.
+/// Subtracting two points gives the size between them.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+pub struct Size {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Smaller of each coordinate.
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Bigger of each coordinate.
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl Size {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(width: i32, height: i32) -> Self {
+        Self { width, height }
+    }
+
+    /// Smaller of each dimension.
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.width.min(other.width), self.height.min(other.height))
+    }
+
+    /// Bigger of each dimension.
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.width.max(other.width), self.height.max(other.height))
+    }
+}
+
+impl From<Vec2> for Point {
+    fn from((x, y): Vec2) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Point> for Vec2 {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl From<Vec2> for Size {
+    fn from((width, height): Vec2) -> Self {
+        Self::new(width, height)
+    }
+}
+
+impl From<Size> for Vec2 {
+    fn from(size: Size) -> Self {
+        (size.width, size.height)
+    }
+}
+
+impl Add<Size> for Point {
+    type Output = Point;
+
+    fn add(self, size: Size) -> Point {
+        Point::new(self.x + size.width, self.y + size.height)
+    }
+}
+
+impl Sub<Size> for Point {
+    type Output = Point;
+
+    fn sub(self, size: Size) -> Point {
+        Point::new(self.x - size.width, self.y - size.height)
+    }
+}
+
+impl Sub for Point {
+    type Output = Size;
+
+    fn sub(self, other: Point) -> Size {
+        Size::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, scalar: i32) -> Point {
+        Point::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Add for Size {
+    type Output = Size;
+
+    fn add(self, other: Size) -> Size {
+        Size::new(self.width + other.width, self.height + other.height)
+    }
+}
+
+impl Sub for Size {
+    type Output = Size;
+
+    fn sub(self, other: Size) -> Size {
+        Size::new(self.width - other.width, self.height - other.height)
+    }
+}
+
+impl Mul<i32> for Size {
+    type Output = Size;
+
+    fn mul(self, scalar: i32) -> Size {
+        Size::new(self.width * scalar, self.height * scalar)
+    }
+}
+
+impl Neg for Size {
+    type Output = Size;
+
+    fn neg(self) -> Size {
+        Size::new(-self.width, -self.height)
+    }
+}
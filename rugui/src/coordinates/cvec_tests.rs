@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::cvec::*;
+
+    #[test]
+    fn test_point_size_ops() {
+        let start = Point::new(3, 4);
+        let end = Point::new(10, 2);
+
+        assert_eq!(end - start, Size::new(7, -2));
+        assert_eq!(start + (end - start), end);
+        assert_eq!(end - Size::new(1, 1), Point::new(9, 1));
+        assert_eq!(start * 2, Point::new(6, 8));
+        assert_eq!(-start, Point::new(-3, -4));
+
+        let size = Size::new(5, 8);
+        assert_eq!(size + Size::new(1, 2), Size::new(6, 10));
+        assert_eq!(size - Size::new(1, 2), Size::new(4, 6));
+        assert_eq!(size * 3, Size::new(15, 24));
+        assert_eq!(-size, Size::new(-5, -8));
+
+        assert_eq!(start.min(end), Point::new(3, 2));
+        assert_eq!(start.max(end), Point::new(10, 4));
+        assert_eq!(size.min(Size::new(6, 2)), Size::new(5, 2));
+        assert_eq!(size.max(Size::new(6, 2)), Size::new(6, 8));
+    }
+
+    #[test]
+    fn test_tuples() {
+        let point: Point = (1, 2).into();
+        let size = Size::from((3, 4));
+        assert_eq!(point, Point::new(1, 2));
+        assert_eq!(Vec2::from(point + size), (4, 6));
+        assert_eq!(Vec2::from(size).scale(2), (6, 8));
+    }
+}
@@ -1,2 +1,4 @@
 pub mod bounding_box;
+mod bounding_box_tests;
 pub mod cvec;
+mod cvec_tests;
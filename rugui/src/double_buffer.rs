@@ -0,0 +1,251 @@
+use super::coordinates::bounding_box::BBox;
+use super::framebuffer::*;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// What happens to the new back buffer after a swap.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum SwapMode {
+    /// Back buffer keeps the frame before the previous one. Use it if every frame is redrawn fully.
+    Discard,
+    /// Back buffer receives a copy of the frame that just became visible,
+    /// so the UI only needs to redraw changed widgets.
+    Copy,
+}
+
+/// State shared between the renderer and the interrupt handler, which exchanges the buffers.
+///
+/// `swap` only flips an atomic flag, so it's cheap and safe to call from an interrupt
+/// while the renderer keeps drawing. Keep it in a `static` to reach it from the handler.
+/// Only atomic loads and stores are used, so it works on targets without CAS (Cortex-M0).
+pub struct SwapHandle {
+    /// `false` if the first buffer is the front one.
+    front: AtomicBool,
+    pending: AtomicBool,
+}
+
+impl Default for SwapHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SwapHandle {
+    pub const fn new() -> Self {
+        Self {
+            front: AtomicBool::new(false),
+            pending: AtomicBool::new(false),
+        }
+    }
+
+    fn front_index(&self) -> usize {
+        self.front.load(Ordering::Acquire) as usize
+    }
+
+    /// Is there a presented frame which is not on the screen yet?
+    pub fn is_pending(&self) -> bool {
+        self.pending.load(Ordering::Acquire)
+    }
+
+    /// Exchange buffers if a frame was presented. Call it when the display driver
+    /// finished reading the front buffer. Returns `true` if the buffers were exchanged.
+    pub fn swap(&self) -> bool {
+        if !self.is_pending() {
+            return false;
+        }
+
+        // Only this method changes the front buffer, so load and store don't race
+        let front = self.front.load(Ordering::Acquire);
+        self.front.store(!front, Ordering::Release);
+        self.pending.store(false, Ordering::Release);
+
+        true
+    }
+}
+
+/// Two framebuffer memories: the UI renders into the back buffer,
+/// while the display driver (e.g. DMA) reads the front one.
+///
+/// The buffers are exchanged by a `SwapHandle`, usually from an interrupt handler.
+/// The renderer side does the rest, e.g. copies the new front buffer into the back one.
+///
+/// ```
+/// # use rugui::double_buffer::{DoubleBuffer, SwapHandle, SwapMode};
+/// # use rugui::framebuffer::{Color, PixelDraw};
+/// static SWAP: SwapHandle = SwapHandle::new();
+///
+/// let (mut a, mut b) = ([0; 128], [0; 128]);
+/// let mut buffers = DoubleBuffer::new(128, 8, &mut a, &mut b, &SWAP, SwapMode::Copy).unwrap();
+///
+/// if let Some(mut back) = buffers.back() {
+///     back.draw_pixel(0, 0, &Color::Black);
+///     buffers.present();
+/// }
+///
+/// // In the "transfer complete" interrupt handler
+/// SWAP.swap();
+///
+/// // The frame is shown, and copied into the back buffer before the next one is drawn
+/// assert_eq!(buffers.front()[0], 1);
+/// assert_eq!(buffers.back().unwrap().get_pixel(0, 0), Color::Black);
+/// ```
+pub struct DoubleBuffer<'a> {
+    width: i32,
+    height: i32,
+    buffers: [&'a mut [u8]; 2],
+    handle: &'a SwapHandle,
+    /// Front buffer the back one was last prepared for.
+    front: usize,
+    mode: SwapMode,
+    rotation: Rotation,
+    mirrored: bool,
+}
+
+impl<'a> DoubleBuffer<'a> {
+    /// Returns `None` if any of the provided buffers is too small.
+    pub fn new(
+        width: i32,
+        height: i32,
+        first: &'a mut [u8],
+        second: &'a mut [u8],
+        handle: &'a SwapHandle,
+        mode: SwapMode,
+    ) -> Option<Self> {
+        Framebuffer::new(width, height, first)?;
+        Framebuffer::new(width, height, second)?;
+
+        Some(Self {
+            width,
+            height,
+            buffers: [first, second],
+            handle,
+            front: handle.front_index(),
+            mode,
+            rotation: Rotation::Deg0,
+            mirrored: false,
+        })
+    }
+
+    /// Set the mounting orientation of the display, see `Framebuffer::rotation`.
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.set_rotation(rotation);
+
+        self
+    }
+
+    /// Mirror the logical coordinate space horizontally. Applied before the rotation.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.set_mirrored(mirrored);
+
+        self
+    }
+
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    pub fn set_mirrored(&mut self, mirrored: bool) {
+        self.mirrored = mirrored;
+    }
+
+    pub fn get_rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    /// Framebuffer to render the next frame into, with the rotation and mirroring of
+    /// the display. `None` while the presented frame waits for the swap.
+    pub fn back(&mut self) -> Option<Framebuffer<'_>> {
+        if self.handle.is_pending() {
+            return None;
+        }
+
+        let front = self.handle.front_index();
+        if front != self.front {
+            self.front = front;
+
+            if self.mode == SwapMode::Copy {
+                let [first, second] = &mut self.buffers;
+                if front == 0 {
+                    second.copy_from_slice(first);
+                } else {
+                    first.copy_from_slice(second);
+                }
+            }
+        }
+
+        // Size was checked in the constructor
+        let back = Framebuffer::new(self.width, self.height, self.buffers[1 - front]).unwrap();
+
+        Some(back.rotation(self.rotation).mirrored(self.mirrored))
+    }
+
+    /// Raw memory of the frame, which is currently shown. Pass it to the display driver.
+    pub fn front(&self) -> &[u8] {
+        self.buffers[self.handle.front_index()]
+    }
+
+    /// Mark the back buffer as complete. The buffers will be exchanged on the next `swap`.
+    pub fn present(&self) {
+        self.handle.pending.store(true, Ordering::Release);
+    }
+
+    /// Is there a presented frame which is not on the screen yet?
+    pub fn is_pending(&self) -> bool {
+        self.handle.is_pending()
+    }
+
+    /// Regions which differ between the back and the front buffers, one per
+    /// 8 pixel high page. Useful to update only a part of the display.
+    /// Regions are in the physical coordinates of the display, without the rotation.
+    pub fn diff(&self) -> DiffIterator<'_> {
+        let front = self.handle.front_index();
+
+        DiffIterator {
+            front: self.buffers[front],
+            back: self.buffers[1 - front],
+            width: self.width,
+            height: self.height,
+            page: 0,
+        }
+    }
+}
+
+pub struct DiffIterator<'b> {
+    front: &'b [u8],
+    back: &'b [u8],
+    width: i32,
+    height: i32,
+    page: i32,
+}
+
+impl<'b> Iterator for DiffIterator<'b> {
+    type Item = BBox;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.page * 8 < self.height {
+            let page = self.page;
+            self.page += 1;
+
+            let start = (page * self.width) as usize;
+            let end = start + self.width as usize;
+            let front = &self.front[start..end];
+            let back = &self.back[start..end];
+
+            let changed = |(f, b): (&u8, &u8)| f != b;
+            let first = front.iter().zip(back).position(changed);
+            let last = front.iter().zip(back).rposition(changed);
+
+            if let (Some(first), Some(last)) = (first, last) {
+                let y = page * 8;
+                let bottom = (y + 7).min(self.height - 1);
+
+                return Some(BBox::new((first as i32, y), (last as i32, bottom)));
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use crate::double_buffer::{DoubleBuffer, SwapHandle, SwapMode};
+    use crate::framebuffer::{Color, Framebuffer, PixelDraw, Rotation};
+
+    #[test]
+    fn test_swap() {
+        let handle = SwapHandle::new();
+        let (mut a, mut b) = ([0; 16], [0; 16]);
+        let mut buffers =
+            DoubleBuffer::new(8, 16, &mut a, &mut b, &handle, SwapMode::Discard).unwrap();
+
+        assert!(!handle.swap());
+        buffers.back().unwrap().draw_pixel(1, 0, &Color::Black);
+        assert_eq!(buffers.front()[1], 0);
+
+        buffers.present();
+        assert!(buffers.is_pending());
+        assert!(buffers.back().is_none());
+        assert!(handle.swap());
+        assert!(!buffers.is_pending());
+        assert_eq!(buffers.front()[1], 1);
+        assert_eq!(buffers.back().unwrap().get_pixel(1, 0), Color::White);
+    }
+
+    #[test]
+    fn test_swap_copy() {
+        let handle = SwapHandle::new();
+        let (mut a, mut b) = ([0; 16], [0; 16]);
+        let mut buffers =
+            DoubleBuffer::new(8, 16, &mut a, &mut b, &handle, SwapMode::Copy).unwrap();
+
+        buffers.back().unwrap().draw_pixel(1, 0, &Color::Black);
+        buffers.present();
+        handle.swap();
+        assert_eq!(buffers.back().unwrap().get_pixel(1, 0), Color::Black);
+    }
+
+    #[test]
+    fn test_swap_from_interrupt() {
+        static HANDLE: SwapHandle = SwapHandle::new();
+        let (mut a, mut b) = ([0; 16], [0; 16]);
+        let mut buffers =
+            DoubleBuffer::new(8, 16, &mut a, &mut b, &HANDLE, SwapMode::Copy).unwrap();
+
+        // The interrupt handler only needs the handle, even while a frame is being drawn
+        let interrupt = || HANDLE.swap();
+
+        let mut back = buffers.back().unwrap();
+        back.draw_pixel(1, 0, &Color::Black);
+        assert!(!interrupt());
+        buffers.present();
+
+        assert!(interrupt());
+        assert!(!interrupt());
+        assert_eq!(buffers.front()[1], 1);
+
+        // Two frames in a row, the copy keeps the first one
+        let mut back = buffers.back().unwrap();
+        back.draw_pixel(2, 0, &Color::Black);
+        buffers.present();
+        assert!(interrupt());
+        assert_eq!(buffers.front()[1..3], [1, 1]);
+
+        let back = buffers.back().unwrap();
+        assert_eq!(back.get_pixel(1, 0), Color::Black);
+        assert_eq!(back.get_pixel(2, 0), Color::Black);
+    }
+
+    #[test]
+    fn test_swap_rotated() {
+        let handle = SwapHandle::new();
+        let (mut a, mut b) = ([0; 16], [0; 16]);
+        let mut buffers = DoubleBuffer::new(8, 16, &mut a, &mut b, &handle, SwapMode::Copy)
+            .unwrap()
+            .rotation(Rotation::Deg90)
+            .mirrored(true);
+
+        let mut back = buffers.back().unwrap();
+        assert_eq!((back.get_width(), back.get_height()), (16, 8));
+        back.draw_pixel(1, 0, &Color::Black);
+        buffers.present();
+        handle.swap();
+
+        let mut expected = [0; 16];
+        Framebuffer::new(8, 16, &mut expected)
+            .unwrap()
+            .rotation(Rotation::Deg90)
+            .mirrored(true)
+            .draw_pixel(1, 0, &Color::Black);
+        assert_eq!(buffers.front(), expected);
+        assert_ne!(buffers.front()[1], 1);
+        assert_eq!(buffers.back().unwrap().get_pixel(1, 0), Color::Black);
+    }
+
+    #[test]
+    fn test_diff() {
+        let handle = SwapHandle::new();
+        let (mut a, mut b) = ([0; 16], [0; 16]);
+        let mut buffers =
+            DoubleBuffer::new(8, 16, &mut a, &mut b, &handle, SwapMode::Copy).unwrap();
+        assert_eq!(buffers.diff().count(), 0);
+
+        let mut back = buffers.back().unwrap();
+        back.draw_pixel(2, 9, &Color::Black);
+        back.draw_pixel(5, 12, &Color::Black);
+
+        let mut diff = buffers.diff();
+        let region = diff.next().unwrap();
+        assert_eq!((region.start, region.end), ((2, 8), (5, 15)));
+        assert!(diff.next().is_none());
+    }
+
+    #[test]
+    fn test_too_small() {
+        let handle = SwapHandle::new();
+        let (mut a, mut b) = ([0; 16], [0; 8]);
+        assert!(DoubleBuffer::new(8, 16, &mut a, &mut b, &handle, SwapMode::Copy).is_none());
+    }
+}
@@ -0,0 +1,292 @@
+//! Q16.16 fixed-point numbers and integer math: trigonometry, square roots and
+//! interpolation, so nothing needs an FPU or soft-float routines.
+
+use crate::coordinates::cvec::Vec2;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Number with 16 integer and 16 fractional bits.
+///
+/// Like with `i32`, arithmetic doesn't saturate, so values must stay within `-32768..32768`.
+/// Multiplication and division round towards negative infinity.
+///
+/// ```
+/// # use rugui::fixed::Fixed;
+/// let half = Fixed::from_ratio(1, 2);
+/// assert_eq!((Fixed::from_int(3) * half).round(), 2);
+/// assert_eq!((Fixed::from_int(3) * half).floor(), 1);
+/// assert_eq!(Fixed::from_int(2).sqrt(), Fixed::from_bits(92681));
+/// ```
+#[derive(Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+    pub const HALF: Self = Self(1 << (Self::FRAC_BITS - 1));
+
+    pub const fn from_int(value: i32) -> Self {
+        Self(value << Self::FRAC_BITS)
+    }
+
+    /// Number with the raw representation, i.e. `bits / 65536`.
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// `numerator / denominator`, rounded to the nearest representable number.
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        let value = ((numerator as i64) << (Self::FRAC_BITS + 1)) / denominator as i64;
+
+        Self(((value + 1) >> 1) as i32)
+    }
+
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Biggest integer not greater than the number.
+    pub const fn floor(self) -> i32 {
+        self.0 >> Self::FRAC_BITS
+    }
+
+    /// Smallest integer not less than the number.
+    pub const fn ceil(self) -> i32 {
+        (self.0 + Self::ONE.0 - 1) >> Self::FRAC_BITS
+    }
+
+    /// Nearest integer, halves are rounded up.
+    pub const fn round(self) -> i32 {
+        (self.0 + Self::HALF.0) >> Self::FRAC_BITS
+    }
+
+    /// Part after the point, always positive.
+    pub const fn frac(self) -> Self {
+        Self(self.0 & (Self::ONE.0 - 1))
+    }
+
+    pub const fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Square root, negative numbers give zero.
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+
+        Self(isqrt((self.0 as u64) << Self::FRAC_BITS) as i32)
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0 + other.0)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0 - other.0)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, other: Fixed) -> Fixed {
+        Fixed(((self.0 as i64 * other.0 as i64) >> Fixed::FRAC_BITS) as i32)
+    }
+}
+
+impl Mul<i32> for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, scalar: i32) -> Fixed {
+        Fixed(self.0 * scalar)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Fixed) -> Fixed {
+        let value = ((self.0 as i64) << Fixed::FRAC_BITS).div_euclid(other.0 as i64);
+
+        Fixed(value as i32)
+    }
+}
+
+impl Div<i32> for Fixed {
+    type Output = Fixed;
+
+    fn div(self, scalar: i32) -> Fixed {
+        Fixed(self.0.div_euclid(scalar))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Fixed {
+        Fixed(-self.0)
+    }
+}
+
+/// Sine of `0..=90` degrees as raw `Fixed` bits.
+#[rustfmt::skip]
+static SIN_TABLE: [i32; 91] = [
+    0, 1144, 2287, 3430, 4572, 5712, 6850, 7987, 9121, 10252,
+    11380, 12505, 13626, 14742, 15855, 16962, 18064, 19161, 20252, 21336,
+    22415, 23486, 24550, 25607, 26656, 27697, 28729, 29753, 30767, 31772,
+    32768, 33754, 34729, 35693, 36647, 37590, 38521, 39441, 40348, 41243,
+    42126, 42995, 43852, 44695, 45525, 46341, 47143, 47930, 48703, 49461,
+    50203, 50931, 51643, 52339, 53020, 53684, 54332, 54963, 55578, 56175,
+    56756, 57319, 57865, 58393, 58903, 59396, 59870, 60326, 60764, 61183,
+    61584, 61966, 62328, 62672, 62997, 63303, 63589, 63856, 64104, 64332,
+    64540, 64729, 64898, 65048, 65177, 65287, 65376, 65446, 65496, 65526,
+    65536,
+];
+
+/// Arctangent of `0/32..=32/32` in degrees as raw `Fixed` bits.
+#[rustfmt::skip]
+static ATAN_TABLE: [i32; 33] = [
+    0, 117304, 234379, 350999, 466945, 582003,
+    695970, 808654, 919879, 1029481, 1137313, 1243245,
+    1347161, 1448965, 1548575, 1645926, 1740967, 1833663,
+    1923990, 2011937, 2097505, 2180703, 2261551, 2340074,
+    2416306, 2490285, 2562055, 2631664, 2699161, 2764600,
+    2828035, 2889523, 2949120,
+];
+
+/// Sine of a whole number of degrees as raw bits.
+fn sin_degrees(degrees: i32) -> i32 {
+    let angle = degrees.rem_euclid(360);
+    let value = |angle: i32| SIN_TABLE[angle as usize];
+
+    match angle {
+        0..=90 => value(angle),
+        91..=180 => value(180 - angle),
+        181..=270 => -value(angle - 180),
+        _ => -value(360 - angle),
+    }
+}
+
+/// Sine of the angle in degrees. Fractions of a degree are interpolated.
+pub fn sin(degrees: impl Into<Fixed>) -> Fixed {
+    let degrees = degrees.into();
+    let whole = degrees.floor();
+    let (from, to) = (sin_degrees(whole), sin_degrees(whole + 1));
+
+    Fixed(from) + Fixed(to - from) * degrees.frac()
+}
+
+/// Cosine of the angle in degrees. Fractions of a degree are interpolated.
+pub fn cos(degrees: impl Into<Fixed>) -> Fixed {
+    sin(degrees.into() + Fixed::from_int(90))
+}
+
+/// Angle in degrees from the positive X axis to the point, counterclockwise with the
+/// Y axis pointing up, in `-180..=180`. Both zero give zero.
+///
+/// ```
+/// # use rugui::fixed::{atan2, Fixed};
+/// assert_eq!(atan2(1, 1), Fixed::from_int(45));
+/// assert_eq!(atan2(-5, 0), Fixed::from_int(-90));
+/// assert_eq!(atan2(0, -3), Fixed::from_int(180));
+/// ```
+pub fn atan2(y: i32, x: i32) -> Fixed {
+    let (ax, ay) = (x.unsigned_abs() as u64, y.unsigned_abs() as u64);
+    if ax == 0 && ay == 0 {
+        return Fixed::ZERO;
+    }
+
+    // Reduce to the first octant, where the ratio is within 0..=1
+    let (small, big) = (ax.min(ay), ax.max(ay));
+    let ratio = ((small << 21) / big) as usize; // 32 table steps with 16 fractional bits
+    let (index, frac) = (ratio >> 16, (ratio & 0xFFFF) as i32);
+    let from = ATAN_TABLE[index];
+    let to = ATAN_TABLE[(index + 1).min(32)];
+    let mut angle = Fixed(from) + Fixed(to - from) * Fixed(frac);
+
+    if ay > ax {
+        angle = Fixed::from_int(90) - angle;
+    }
+    if x < 0 {
+        angle = Fixed::from_int(180) - angle;
+    }
+    if y < 0 {
+        angle = -angle;
+    }
+
+    angle
+}
+
+/// Square root rounded down.
+pub fn isqrt(value: u64) -> u32 {
+    let mut rest = value;
+    let mut root = 0u64;
+    let mut bit = 1u64 << 62;
+    while bit > value {
+        bit >>= 2;
+    }
+
+    while bit != 0 {
+        if rest >= root + bit {
+            rest -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+
+    root as u32
+}
+
+/// Value at `t` between `from` at zero and `to` at one, rounded to the nearest integer.
+pub fn lerp(from: i32, to: i32, t: Fixed) -> i32 {
+    from + (Fixed::from_int(to - from) * t).round()
+}
+
+/// Value mapped from the range `from` to the range `to`, rounded towards `to.0`. Values
+/// outside of `from` are clamped. If `from` is empty, the value counts as its end.
+///
+/// ```
+/// # use rugui::fixed::map;
+/// assert_eq!(map(50, (0, 100), (10, 20)), 15);
+/// assert_eq!(map(150, (0, 100), (270, 90)), 90);
+/// assert_eq!(map(5, (5, 5), (0, 64)), 64);
+/// ```
+pub fn map(value: i32, from: (i32, i32), to: (i32, i32)) -> i32 {
+    let range = from.1 as i64 - from.0 as i64;
+    if range == 0 {
+        return to.1;
+    }
+
+    let offset = value.clamp(from.0.min(from.1), from.0.max(from.1)) as i64 - from.0 as i64;
+
+    to.0 + ((to.1 as i64 - to.0 as i64) * offset / range) as i32
+}
+
+/// Point at the `distance` from the `center` in the direction of the angle. Angles go
+/// counterclockwise from the positive X axis. The screen Y axis points down,
+/// so 90 degrees is straight up.
+pub fn polar(center: Vec2, distance: i32, degrees: i32) -> Vec2 {
+    (
+        center.0 + (cos(degrees) * distance).round(),
+        center.1 - (sin(degrees) * distance).round(),
+    )
+}
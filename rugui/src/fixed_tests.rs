@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use crate::fixed::*;
+
+    #[test]
+    fn test_fixed() {
+        let a = Fixed::from_ratio(3, 2);
+        let b = Fixed::from_int(-2);
+
+        assert_eq!(a + b, Fixed::from_ratio(-1, 2));
+        assert_eq!(a - b, Fixed::from_ratio(7, 2));
+        assert_eq!(a * b, Fixed::from_int(-3));
+        assert_eq!(b / a, Fixed::from_bits(-87382));
+        assert_eq!(a * 4, Fixed::from_int(6));
+        assert_eq!(a / 3, Fixed::HALF);
+        assert_eq!(-a, Fixed::from_ratio(-3, 2));
+
+        assert_eq!((a.floor(), a.ceil(), a.round()), (1, 2, 2));
+        assert_eq!(((-a).floor(), (-a).ceil(), (-a).round()), (-2, -1, -1));
+        assert_eq!((-a).frac(), Fixed::HALF);
+        assert_eq!(Fixed::from_int(9).sqrt(), Fixed::from_int(3));
+        assert_eq!(b.sqrt(), Fixed::ZERO);
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        assert_eq!(sin(0), Fixed::ZERO);
+        assert_eq!(sin(90), Fixed::ONE);
+        assert_eq!(sin(-90), -Fixed::ONE);
+        assert_eq!(sin(210), -Fixed::HALF);
+        assert_eq!(cos(180), -Fixed::ONE);
+        assert_eq!(cos(420), Fixed::HALF);
+
+        // Between whole degrees the table is interpolated
+        let half = sin(Fixed::from_ratio(61, 2));
+        assert!(half > sin(30) && half < sin(31));
+        assert_eq!(sin(Fixed::from_ratio(-1, 2)), -sin(Fixed::HALF));
+    }
+
+    #[test]
+    fn test_atan2() {
+        assert_eq!(atan2(0, 0), Fixed::ZERO);
+        assert_eq!(atan2(0, 5), Fixed::ZERO);
+        assert_eq!(atan2(3, 0), Fixed::from_int(90));
+        assert_eq!(atan2(-1, -1), Fixed::from_int(-135));
+        assert_eq!(atan2(1, -1), Fixed::from_int(135));
+
+        // Within a tenth of a degree everywhere around the circle
+        for degrees in -179..=180 {
+            let (x, y) = ((cos(degrees) * 1000).round(), (sin(degrees) * 1000).round());
+            let error = (atan2(y, x) - Fixed::from_int(degrees)).abs();
+            assert!(error < Fixed::from_ratio(1, 10), "{degrees}: {error:?}");
+        }
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX), u32::MAX);
+    }
+
+    #[test]
+    fn test_interpolation() {
+        assert_eq!(lerp(10, 20, Fixed::HALF), 15);
+        assert_eq!(lerp(20, 10, Fixed::from_ratio(1, 4)), 18);
+        assert_eq!(lerp(0, 10, Fixed::from_int(2)), 20);
+
+        assert_eq!(map(0, (0, 100), (0, 10)), 0);
+        assert_eq!(map(99, (0, 100), (0, 10)), 9);
+        assert_eq!(map(-5, (0, 100), (0, 10)), 0);
+        assert_eq!(map(25, (100, 0), (0, 8)), 6);
+    }
+
+    #[test]
+    fn test_polar() {
+        assert_eq!(polar((10, 10), 5, 0), (15, 10));
+        assert_eq!(polar((10, 10), 5, 90), (10, 5));
+        assert_eq!(polar((10, 10), 10, 225), (3, 17));
+    }
+}
@@ -0,0 +1,338 @@
+use crate::coordinates::bounding_box::BBox;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::widgets::{Widget, WidgetList};
+
+/// Focus state of a widget.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Focus {
+    None,
+    /// Widget is selected by navigation.
+    Focused,
+    /// Widget captured the input, e.g. to edit its value.
+    Editing,
+}
+
+/// How a focused widget is highlighted.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum FocusStyle {
+    /// Rectangle 1 pixel outside of the widget.
+    Outline,
+    /// The whole widget is drawn inverted.
+    Invert,
+}
+
+/// Keeps track of the focused widget in a `WidgetList` and moves the focus
+/// with navigation keys or an encoder.
+///
+/// Navigation skips widgets which aren't focusable. `Enter` on an editable widget
+/// captures the input: all events go to that widget until `Enter` or `Back` is pressed.
+/// A captured widget can consume `Enter` or `Back` to stay captured, e.g. to move to the
+/// next field or to leave a submenu.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::focus::FocusChain;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::input::{Event, Key};
+/// # use rugui::widgets::{Column, ColumnWidth, Table};
+/// # let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+/// # let rows: [&[&str]; 2] = [&["0"], &["1"]];
+/// # let table = |x| Table::new(BBox::new((x, 0), (x + 30, 40)), &columns, &rows, Color::Black);
+/// let mut widgets = [table(0), table(31)];
+/// let mut focus = FocusChain::new();
+///
+/// focus.route(&mut widgets, &Event::KeyPress(Key::Down));
+/// assert_eq!(focus.current(), Some(0));
+/// focus.route(&mut widgets, &Event::Encoder(1));
+/// assert_eq!(focus.current(), Some(1));
+/// ```
+pub struct FocusChain {
+    current: Option<usize>,
+    columns: usize,
+    wrap: bool,
+    captured: bool,
+}
+
+impl Default for FocusChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FocusChain {
+    pub const fn new() -> Self {
+        Self {
+            current: None,
+            columns: 1,
+            wrap: true,
+            captured: false,
+        }
+    }
+
+    /// Widgets are arranged in a grid with this number of columns. `Up` and `Down` keys
+    /// move the focus by a whole row, `Left` and `Right` move it by one widget.
+    pub const fn columns(mut self, columns: usize) -> Self {
+        self.columns = if columns == 0 { 1 } else { columns };
+
+        self
+    }
+
+    /// Move the focus from the last widget to the first one and back.
+    pub const fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+
+        self
+    }
+
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    pub fn is_captured(&self) -> bool {
+        self.captured
+    }
+
+    /// Focus state of the widget with the `index`.
+    pub fn focus_of(&self, index: usize) -> Focus {
+        match (self.current == Some(index), self.captured) {
+            (false, _) => Focus::None,
+            (true, false) => Focus::Focused,
+            (true, true) => Focus::Editing,
+        }
+    }
+
+    /// Focus the widget with the `index`, or remove the focus if it's `None`.
+    pub fn focus<L: WidgetList + ?Sized>(&mut self, widgets: &mut L, index: Option<usize>) {
+        let index = index.filter(|&i| i < widgets.len() && widgets.is_focusable(i));
+        if index == self.current && !self.captured {
+            return;
+        }
+
+        if let Some(current) = self.current.filter(|&i| i < widgets.len()) {
+            widgets.set_focus(current, Focus::None);
+        }
+
+        self.current = index;
+        self.captured = false;
+
+        if let Some(index) = index {
+            widgets.set_focus(index, Focus::Focused);
+        }
+    }
+
+    /// Focus the next focusable widget.
+    pub fn next<L: WidgetList + ?Sized>(&mut self, widgets: &mut L) {
+        self.step(widgets, 1);
+    }
+
+    /// Focus the previous focusable widget.
+    pub fn prev<L: WidgetList + ?Sized>(&mut self, widgets: &mut L) {
+        self.step(widgets, -1);
+    }
+
+    pub fn up<L: WidgetList + ?Sized>(&mut self, widgets: &mut L) {
+        self.step(widgets, -(self.columns as isize));
+    }
+
+    pub fn down<L: WidgetList + ?Sized>(&mut self, widgets: &mut L) {
+        self.step(widgets, self.columns as isize);
+    }
+
+    /// Let the focused widget receive all events, e.g. to edit its value.
+    pub fn capture<L: WidgetList + ?Sized>(&mut self, widgets: &mut L) {
+        if let Some(current) = self.focused(widgets) {
+            self.captured = true;
+            widgets.set_focus(current, Focus::Editing);
+        }
+    }
+
+    pub fn release<L: WidgetList + ?Sized>(&mut self, widgets: &mut L) {
+        if let Some(current) = self.focused(widgets) {
+            widgets.set_focus(current, Focus::Focused);
+        }
+        self.captured = false;
+    }
+
+    /// Draw the widgets, the focused one with the focus indicator.
+    pub fn draw<L, C>(&self, widgets: &L, canvas: &mut C, style: FocusStyle, color: Color)
+    where
+        L: WidgetList + ?Sized,
+        C: PixelDraw,
+    {
+        for index in 0..widgets.len() {
+            let entry = Entry { widgets, index };
+            draw_indicator(
+                &entry,
+                widgets.bbox(index),
+                canvas,
+                self.focus_of(index),
+                style,
+                color,
+            );
+        }
+    }
+
+    /// Index of the focused widget if it's in the list.
+    fn focused<L: WidgetList + ?Sized>(&self, widgets: &L) -> Option<usize> {
+        self.current.filter(|&i| i < widgets.len())
+    }
+
+    fn step<L: WidgetList + ?Sized>(&mut self, widgets: &mut L, delta: isize) {
+        let len = widgets.len() as isize;
+        if len == 0 {
+            return;
+        }
+
+        let mut index = match self.current {
+            Some(current) => current as isize,
+            // The first step lands on the first or the last widget
+            None if delta > 0 => -1,
+            None => len,
+        };
+
+        for _ in 0..len {
+            index += delta;
+            if index < 0 || index >= len {
+                if !self.wrap {
+                    return;
+                }
+                index = index.rem_euclid(len);
+            }
+
+            if widgets.is_focusable(index as usize) {
+                self.focus(widgets, Some(index as usize));
+                return;
+            }
+        }
+    }
+
+    /// Handle the event: navigation keys move the focus, other events go to the
+    /// focused widget. Touch events focus and go to the widget under the point.
+    /// Returns `true` if the event was consumed.
+    pub fn route<L: WidgetList + ?Sized>(&mut self, widgets: &mut L, event: &Event) -> bool {
+        if let Some(point) = event.position() {
+            if let Some(target) = widgets.position_at(point) {
+                if matches!(event, Event::TouchDown(_)) && self.current != Some(target) {
+                    self.focus(widgets, Some(target));
+                }
+
+                return widgets.handle_event(target, event);
+            }
+
+            return false;
+        }
+
+        if self.captured {
+            let consumed = match self.focused(widgets) {
+                Some(current) => widgets.handle_event(current, event),
+                None => false,
+            };
+
+            return match event {
+                Event::KeyPress(Key::Enter | Key::EncoderPush | Key::Back) if !consumed => {
+                    self.release(widgets);
+                    true
+                }
+                _ => consumed,
+            };
+        }
+
+        match event {
+            Event::KeyPress(Key::Up) | Event::KeyRepeat(Key::Up) => self.up(widgets),
+            Event::KeyPress(Key::Down) | Event::KeyRepeat(Key::Down) => self.down(widgets),
+            Event::KeyPress(Key::Left) | Event::KeyRepeat(Key::Left) => self.prev(widgets),
+            Event::KeyPress(Key::Right) | Event::KeyRepeat(Key::Right) => self.next(widgets),
+            Event::Encoder(steps) => {
+                for _ in 0..steps.unsigned_abs() {
+                    self.step(widgets, steps.signum() as isize);
+                }
+            }
+            Event::KeyPress(Key::Enter | Key::EncoderPush) => {
+                let current = match self.focused(widgets) {
+                    Some(current) => current,
+                    None => return false,
+                };
+
+                if widgets.is_editable(current) {
+                    self.capture(widgets);
+                    return true;
+                }
+
+                return widgets.handle_event(current, event);
+            }
+            _ => {
+                return match self.focused(widgets) {
+                    Some(current) => widgets.handle_event(current, event),
+                    None => false,
+                };
+            }
+        }
+
+        true
+    }
+}
+
+/// A widget of a list, drawn by `FocusChain::draw`.
+struct Entry<'a, L: ?Sized> {
+    widgets: &'a L,
+    index: usize,
+}
+
+impl<L: WidgetList + ?Sized> Drawable for Entry<'_, L> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        self.widgets.draw_widget(self.index, canvas);
+    }
+}
+
+/// Draw a widget with the focus indicator.
+pub fn draw_focused<W, C>(widget: &W, canvas: &mut C, focus: Focus, style: FocusStyle, color: Color)
+where
+    W: Widget,
+    C: PixelDraw,
+{
+    draw_indicator(widget, widget.bbox(), canvas, focus, style, color);
+}
+
+fn draw_indicator<D, C>(
+    item: &D,
+    bbox: BBox,
+    canvas: &mut C,
+    focus: Focus,
+    style: FocusStyle,
+    color: Color,
+) where
+    D: Drawable,
+    C: PixelDraw,
+{
+    if focus == Focus::None {
+        item.draw(canvas);
+        return;
+    }
+
+    match style {
+        FocusStyle::Outline => {
+            item.draw(canvas);
+
+            let outline = BBox::new(
+                (bbox.start.0 - 1, bbox.start.1 - 1),
+                (bbox.end.0 + 1, bbox.end.1 + 1),
+            );
+            Rect::new(outline, color).draw(canvas);
+        }
+        FocusStyle::Invert => {
+            Rect::new_filled(bbox, color).draw(canvas);
+            item.draw(&mut Inverted::new(canvas));
+        }
+    }
+
+    // Editing is shown with a double outline
+    if focus == Focus::Editing {
+        let outline = BBox::new(
+            (bbox.start.0 - 2, bbox.start.1 - 2),
+            (bbox.end.0 + 2, bbox.end.1 + 2),
+        );
+        Rect::new(outline, color).draw(canvas);
+    }
+}
@@ -0,0 +1,216 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::bounding_box::BBox;
+    use crate::coordinates::cvec::Point;
+    use crate::focus::{draw_focused, Focus, FocusChain, FocusStyle};
+    use crate::framebuffer::{Color, Framebuffer, Orientation};
+    use crate::input::{Event, Key};
+    use crate::widgets::{Button, Column, ColumnWidth, Menu, MenuItem, MenuItems, Scroller};
+    use crate::widgets::{Slider, TabView, Table, Widget};
+
+    const ROWS: [&[&str]; 3] = [&["0"], &["1"], &["2"]];
+
+    fn tables<'a>(columns: &'a [Column<'a>]) -> [Table<'a>; 4] {
+        let table = |x: i32, y: i32| {
+            Table::new(
+                BBox::new((x, y), (x + 30, y + 30)),
+                columns,
+                &ROWS,
+                Color::Black,
+            )
+        };
+
+        [table(0, 0), table(40, 0), table(0, 40), table(40, 40)]
+    }
+
+    #[test]
+    fn test_navigation() {
+        let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+        let mut widgets = tables(&columns);
+        let mut focus = FocusChain::new();
+
+        assert!(focus.route(&mut widgets, &Event::KeyPress(Key::Up)));
+        assert_eq!(focus.current(), Some(3));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Down));
+        assert_eq!(focus.current(), Some(0));
+        focus.route(&mut widgets, &Event::Encoder(2));
+        assert_eq!(focus.current(), Some(2));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Left));
+        assert_eq!(focus.current(), Some(1));
+    }
+
+    #[test]
+    fn test_grid_navigation() {
+        let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+        let mut widgets = tables(&columns);
+        let mut focus = FocusChain::new().columns(2).wrap(false);
+
+        focus.focus(&mut widgets, Some(1));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Down));
+        assert_eq!(focus.current(), Some(3));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Down));
+        assert_eq!(focus.current(), Some(3));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Left));
+        assert_eq!(focus.current(), Some(2));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Up));
+        assert_eq!(focus.current(), Some(0));
+    }
+
+    #[test]
+    fn test_skips_not_focusable() {
+        let scroller = |x| {
+            Scroller::new(
+                BBox::new((x, 0), (x + 3, 30)),
+                0,
+                1,
+                2,
+                Orientation::Vertical,
+                Color::Black,
+            )
+        };
+        let mut widgets = [scroller(0), scroller(10)];
+        let mut focus = FocusChain::new();
+
+        focus.route(&mut widgets, &Event::KeyPress(Key::Down));
+        assert_eq!(focus.current(), None);
+    }
+
+    #[test]
+    fn test_capture() {
+        let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+        let mut widgets = tables(&columns);
+        let mut focus = FocusChain::new();
+        focus.focus(&mut widgets, Some(1));
+
+        focus.route(&mut widgets, &Event::KeyPress(Key::Enter));
+        assert!(focus.is_captured());
+        assert_eq!(focus.focus_of(1), Focus::Editing);
+
+        focus.route(&mut widgets, &Event::KeyPress(Key::Down));
+        focus.route(&mut widgets, &Event::Encoder(1));
+        assert_eq!(focus.current(), Some(1));
+        assert_eq!(widgets[1].get_selected(), Some(1));
+
+        focus.route(&mut widgets, &Event::KeyPress(Key::Back));
+        assert!(!focus.is_captured());
+        assert_eq!(focus.focus_of(1), Focus::Focused);
+        assert_eq!(focus.focus_of(0), Focus::None);
+    }
+
+    #[test]
+    fn test_capture_back() {
+        const SUBMENU: [MenuItem<'static>; 1] = [MenuItem::new("Contrast")];
+        const ROOT: [MenuItem<'static>; 1] = [MenuItem::submenu("Display", &SUBMENU)];
+        let menu = Menu::new(
+            BBox::new((0, 0), (0, 0)),
+            MenuItems::Tree(&ROOT),
+            Color::Black,
+        );
+        let bbox = BBox::new((0, 0), (63, 63));
+        let mut widgets = [TabView::new(bbox, ["Menu"], [menu], Color::Black)];
+        let mut focus = FocusChain::new();
+        focus.focus(&mut widgets, Some(0));
+
+        focus.route(&mut widgets, &Event::KeyPress(Key::Enter));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Enter));
+        assert_eq!(widgets[0].pages()[0].path(), &[0, 0]);
+
+        // The menu consumes `Back` to leave the submenu, the input stays captured
+        focus.route(&mut widgets, &Event::KeyPress(Key::Back));
+        assert!(focus.is_captured());
+        assert_eq!(widgets[0].pages()[0].path(), &[0]);
+
+        focus.route(&mut widgets, &Event::KeyPress(Key::Back));
+        assert!(!focus.is_captured());
+    }
+
+    #[test]
+    fn test_touch_focus() {
+        let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+        let mut widgets = tables(&columns);
+        let mut focus = FocusChain::new();
+
+        focus.route(&mut widgets, &Event::TouchDown(Point::new(45, 45)));
+        assert_eq!(focus.current(), Some(3));
+        assert!(!focus.route(&mut widgets, &Event::TouchDown(Point::new(35, 35))));
+        assert_eq!(focus.current(), Some(3));
+    }
+
+    #[test]
+    fn test_draw_focused() {
+        let mut buffer = [0; 64 * 8];
+        let mut framebuffer = Framebuffer::new(64, 64, &mut buffer).unwrap();
+        let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+        let mut widgets = tables(&columns);
+        widgets[3].mark_drawn();
+
+        let mut focus = FocusChain::new();
+        focus.focus(&mut widgets, Some(3));
+        assert!(widgets[3].needs_redraw());
+
+        let style = FocusStyle::Outline;
+        draw_focused(
+            &widgets[3],
+            &mut framebuffer,
+            Focus::Focused,
+            style,
+            Color::Black,
+        );
+        assert_eq!(framebuffer.get_pixel(39, 39), Color::Black);
+
+        let style = FocusStyle::Invert;
+        draw_focused(
+            &widgets[0],
+            &mut framebuffer,
+            Focus::Focused,
+            style,
+            Color::Black,
+        );
+        assert_eq!(framebuffer.get_pixel(0, 0), Color::White);
+        assert_eq!(framebuffer.get_pixel(20, 25), Color::Black);
+    }
+
+    #[test]
+    fn test_mixed_widgets() {
+        let mut buffer = [0; 64 * 8];
+        let mut framebuffer = Framebuffer::new(64, 64, &mut buffer).unwrap();
+        let scroller = Scroller::new(
+            BBox::new((60, 0), (63, 63)),
+            0,
+            1,
+            2,
+            Orientation::Vertical,
+            Color::Black,
+        );
+        let mut widgets = (
+            Slider::new(BBox::new((2, 2), (40, 8)), 0, 10, 5, Color::Black),
+            scroller,
+            Button::new(BBox::new((2, 20), (40, 32)), "OK", Color::Black),
+        );
+        let mut focus = FocusChain::new();
+
+        focus.route(&mut widgets, &Event::KeyPress(Key::Down));
+        assert_eq!(focus.current(), Some(0));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Enter));
+        focus.route(&mut widgets, &Event::KeyPress(Key::Right));
+        assert_eq!(widgets.0.value(), 6);
+        focus.route(&mut widgets, &Event::KeyPress(Key::Enter));
+        assert!(!focus.is_captured());
+
+        // The scroller isn't focusable
+        focus.route(&mut widgets, &Event::Encoder(1));
+        assert_eq!(focus.current(), Some(2));
+        focus.route(&mut widgets, &Event::TouchDown(Point::new(5, 5)));
+        assert_eq!(focus.current(), Some(0));
+
+        focus.draw(
+            &widgets,
+            &mut framebuffer,
+            FocusStyle::Outline,
+            Color::Black,
+        );
+        assert_eq!(framebuffer.get_pixel(1, 1), Color::Black);
+        assert_eq!(framebuffer.get_pixel(1, 19), Color::White);
+        assert_eq!(framebuffer.get_pixel(2, 20), Color::Black);
+    }
+}
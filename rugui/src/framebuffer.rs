@@ -1,82 +1,516 @@
+use crate::coordinates::bounding_box::BBox;
+use crate::terminal::TerminalRenderer;
+use core::fmt;
+
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum Color {
     Black,
     White,
 }
 
-#[derive(PartialEq)]
+impl Color {
+    pub fn invert(&self) -> Self {
+        match self {
+            Color::Black => Color::White,
+            Color::White => Color::Black,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum Orientation {
     Vertical,
     Horizontal,
 }
 
+/// Clockwise rotation of the display relative to its native scan direction.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
 pub struct Framebuffer<'a> {
     width: i32,
     height: i32,
-    row_height: i32,
+    rotation: Rotation,
+    mirrored: bool,
     frame: &'a mut [u8],
 }
 
+// TODO: Currently hardcoded and I really doubt that we ever encounter displays with 8bit+ data buses
+const ROW_HEIGHT: usize = 8;
+
+/// Number of bytes needed to store a frame of the given size.
+// TODO: This only works for monochrome displays
+pub const fn buffer_size(width: usize, height: usize) -> usize {
+    width * height.div_ceil(ROW_HEIGHT)
+}
+
+/// Write a pixel, using physical coordinates, which must be within the frame.
+fn write_pixel(frame: &mut [u8], width: i32, x: i32, y: i32, color: &Color) {
+    let row_height = ROW_HEIGHT as i32;
+    let pos = (y / row_height * width) + x;
+    let mut byte = frame[pos as usize];
+    let pixel = y % row_height;
+
+    byte = match color {
+        Color::Black => byte | 1 << pixel,
+        Color::White => byte & !(1 << pixel),
+    };
+
+    frame[pos as usize] = byte;
+}
+
+/// Logical size of a `width` by `height` display mounted with the rotation.
+fn logical_size(width: i32, height: i32, rotation: Rotation) -> (i32, i32) {
+    match rotation {
+        Rotation::Deg0 | Rotation::Deg180 => (width, height),
+        Rotation::Deg90 | Rotation::Deg270 => (height, width),
+    }
+}
+
+/// Convert logical coordinates to the physical ones.
+/// Returns `None` if the point lies outside of the display.
+fn to_physical(
+    (width, height): (i32, i32),
+    rotation: Rotation,
+    mirrored: bool,
+    x: i32,
+    y: i32,
+) -> Option<(i32, i32)> {
+    let (logical_width, logical_height) = logical_size(width, height, rotation);
+    if x < 0 || y < 0 || x >= logical_width || y >= logical_height {
+        return None;
+    }
+
+    let x = if mirrored { logical_width - 1 - x } else { x };
+
+    let (w, h) = (width, height);
+    Some(match rotation {
+        Rotation::Deg0 => (x, y),
+        Rotation::Deg90 => (w - 1 - y, x),
+        Rotation::Deg180 => (w - 1 - x, h - 1 - y),
+        Rotation::Deg270 => (y, h - 1 - x),
+    })
+}
+
+/// Read a pixel, using physical coordinates, which must be within the frame.
+fn read_pixel(frame: &[u8], width: i32, x: i32, y: i32) -> Color {
+    let row_height = ROW_HEIGHT as i32;
+    let pos = (y / row_height * width) + x;
+    let byte = frame[pos as usize];
+    let pixel = y % row_height;
+
+    if byte & (1 << pixel) != 0 {
+        Color::Black
+    } else {
+        Color::White
+    }
+}
+
 pub trait PixelDraw {
     fn draw_pixel(&mut self, x: i32, y: i32, color: &Color) -> bool;
 }
 
-impl<'a> PixelDraw for Framebuffer<'a> {
+/// Canvas which can be read back, e.g. to save the area under an overlay.
+pub trait PixelRead {
+    /// Returns `Color::White` for pixels outside of the canvas.
+    fn get_pixel(&self, x: i32, y: i32) -> Color;
+}
+
+/// Canvas adaptor which discards everything outside of the bounding box.
+pub struct Clip<'c, C: PixelDraw> {
+    canvas: &'c mut C,
+    bbox: BBox,
+}
+
+impl<'c, C: PixelDraw> Clip<'c, C> {
+    pub fn new(canvas: &'c mut C, bbox: BBox) -> Self {
+        Self { canvas, bbox }
+    }
+}
+
+impl<'c, C: PixelDraw> PixelDraw for Clip<'c, C> {
     fn draw_pixel(&mut self, x: i32, y: i32, color: &Color) -> bool {
-        if self.width <= x || self.height <= y {
+        let (start, end) = (self.bbox.start, self.bbox.end);
+        if x < start.0 || y < start.1 || x > end.0 || y > end.1 {
             return false;
         }
 
-        let pos = (y / self.row_height * self.width) + x;
-        let mut byte = self.frame[pos as usize];
-        let pixel = y % self.row_height;
+        self.canvas.draw_pixel(x, y, color)
+    }
+}
+
+/// Canvas adaptor which inverts colors of everything drawn through it.
+pub struct Inverted<'c, C: PixelDraw> {
+    canvas: &'c mut C,
+}
 
-        byte = match color {
-            Color::Black => byte | 1 << pixel,
-            Color::White => byte & !(1 << pixel),
+impl<'c, C: PixelDraw> Inverted<'c, C> {
+    pub fn new(canvas: &'c mut C) -> Self {
+        Self { canvas }
+    }
+}
+
+impl<'c, C: PixelDraw> PixelDraw for Inverted<'c, C> {
+    fn draw_pixel(&mut self, x: i32, y: i32, color: &Color) -> bool {
+        self.canvas.draw_pixel(x, y, &color.invert())
+    }
+}
+
+impl<'a> PixelDraw for Framebuffer<'a> {
+    fn draw_pixel(&mut self, x: i32, y: i32, color: &Color) -> bool {
+        let (x, y) = match self.to_physical(x, y) {
+            Some(coordinates) => coordinates,
+            None => return false,
         };
 
-        self.frame[pos as usize] = byte;
+        write_pixel(self.frame, self.width, x, y, color);
 
         true
     }
 }
 
+impl<'a> PixelRead for Framebuffer<'a> {
+    fn get_pixel(&self, x: i32, y: i32) -> Color {
+        Framebuffer::get_pixel(self, x, y)
+    }
+}
+
 impl<'a> Framebuffer<'a> {
     /// Construct a new display. Returns `None` if provided framebuffer is too small.
     pub fn new(width: i32, height: i32, frame: &'a mut [u8]) -> Option<Self> {
-        // TODO: Currently hardcoded and I really doubt that we ever encounter displays with 8bit+ data buses
-        let row_height = 8;
-        // TODO: This only works for monochrome displays
-        if (width * height / row_height) as usize > frame.len() {
+        if width < 0 || height < 0 || buffer_size(width as usize, height as usize) > frame.len() {
             return None;
         }
 
         Some(Framebuffer {
             width,
             height,
-            row_height,
+            rotation: Rotation::Deg0,
+            mirrored: false,
             frame,
         })
     }
 
+    /// Set the mounting orientation of the display.
+    /// All drawing is done in logical coordinates, which are remapped to the physical ones.
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.set_rotation(rotation);
+
+        self
+    }
+
+    /// Mirror the logical coordinate space horizontally. Applied before the rotation.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.set_mirrored(mirrored);
+
+        self
+    }
+
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    pub fn set_mirrored(&mut self, mirrored: bool) {
+        self.mirrored = mirrored;
+    }
+
+    pub fn get_rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    fn to_physical(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        to_physical(
+            (self.width, self.height),
+            self.rotation,
+            self.mirrored,
+            x,
+            y,
+        )
+    }
+
+    /// Returns `Color::White` for pixels outside of the display.
+    pub fn get_pixel(&self, x: i32, y: i32) -> Color {
+        let (x, y) = match self.to_physical(x, y) {
+            Some(coordinates) => coordinates,
+            None => return Color::White,
+        };
+
+        read_pixel(self.frame, self.width, x, y)
+    }
+
+    /// Raw memory in the display format, e.g. to send it to the display.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.frame
+    }
+
+    /// Logical height, which takes rotation into account.
+    pub fn get_height(&self) -> i32 {
+        logical_size(self.width, self.height, self.rotation).1
+    }
+
+    /// Logical width, which takes rotation into account.
+    pub fn get_width(&self) -> i32 {
+        logical_size(self.width, self.height, self.rotation).0
+    }
+}
+
+/// Prints the framebuffer contents as half-block art, so failed `assert_eq!` shows the picture.
+impl<'a> fmt::Debug for Framebuffer<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Framebuffer {}x{}", self.get_width(), self.get_height())?;
+        write!(f, "{}", TerminalRenderer::new(self))
+    }
+}
+
+/// Type of a `StaticFramebuffer` of `width` by `height` pixels, with the buffer size
+/// computed by `buffer_size`.
+#[macro_export]
+macro_rules! static_framebuffer {
+    ($width:expr, $height:expr) => {
+        $crate::framebuffer::StaticFramebuffer<
+            { $width },
+            { $height },
+            { $crate::framebuffer::buffer_size($width, $height) },
+        >
+    };
+}
+
+/// Framebuffer which owns its memory. Dimensions are known at compile time, so it's
+/// impossible to create one with a buffer of the wrong size.
+///
+/// Stable Rust can't compute an array length from other const parameters, so the type
+/// carries the buffer size `N` as well. Name it with `static_framebuffer!(W, H)`, which
+/// derives `N` from the dimensions.
+///
+/// ```
+/// # use rugui::framebuffer::{Color, PixelDraw, Rotation};
+/// # use rugui::static_framebuffer;
+/// let mut display = <static_framebuffer!(128, 64)>::new().rotation(Rotation::Deg90);
+/// display.draw_pixel(10, 10, &Color::Black);
+/// assert_eq!(display.get_width(), 64);
+/// assert_eq!(display.as_bytes().len(), 1024);
+/// ```
+///
+/// A size spelled out by hand is checked when `new` is instantiated:
+///
+/// ```compile_fail,E0080
+/// # use rugui::framebuffer::StaticFramebuffer;
+/// let display = StaticFramebuffer::<128, 64, 1000>::new();
+/// ```
+pub struct StaticFramebuffer<const W: usize, const H: usize, const N: usize> {
+    rotation: Rotation,
+    mirrored: bool,
+    frame: [u8; N],
+}
+
+impl<const W: usize, const H: usize, const N: usize> StaticFramebuffer<W, H, N> {
+    const SIZE_CHECK: () = assert!(
+        N == buffer_size(W, H),
+        "buffer size must be equal to `buffer_size(W, H)`"
+    );
+
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::SIZE_CHECK;
+
+        Self {
+            rotation: Rotation::Deg0,
+            mirrored: false,
+            frame: [0; N],
+        }
+    }
+
+    /// Set the mounting orientation of the display, see `Framebuffer::rotation`.
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.set_rotation(rotation);
+
+        self
+    }
+
+    /// Mirror the logical coordinate space horizontally. Applied before the rotation.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.set_mirrored(mirrored);
+
+        self
+    }
+
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    pub fn set_mirrored(&mut self, mirrored: bool) {
+        self.mirrored = mirrored;
+    }
+
+    pub fn get_rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    /// Borrow as a `Framebuffer` with the same rotation and mirroring.
+    pub fn framebuffer(&mut self) -> Framebuffer<'_> {
+        // Size is checked at compile time
+        Framebuffer::new(W as i32, H as i32, &mut self.frame)
+            .unwrap()
+            .rotation(self.rotation)
+            .mirrored(self.mirrored)
+    }
+
+    fn to_physical(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        to_physical((W as i32, H as i32), self.rotation, self.mirrored, x, y)
+    }
+
+    /// Returns `Color::White` for pixels outside of the display.
     pub fn get_pixel(&self, x: i32, y: i32) -> Color {
-        let pos = (y / self.row_height * self.width) + x;
-        let byte = self.frame[pos as usize];
-        let pixel = y % self.row_height;
-
-        if byte & (1 << pixel) != 0 {
-            Color::Black
-        } else {
-            Color::White
+        match self.to_physical(x, y) {
+            Some((x, y)) => read_pixel(&self.frame, W as i32, x, y),
+            None => Color::White,
         }
     }
 
+    /// Raw memory in the display format, e.g. to send it to the display.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.frame
+    }
+
+    /// Logical height, which takes rotation into account.
     pub fn get_height(&self) -> i32 {
-        self.height
+        logical_size(W as i32, H as i32, self.rotation).1
     }
 
+    /// Logical width, which takes rotation into account.
     pub fn get_width(&self) -> i32 {
-        self.width
+        logical_size(W as i32, H as i32, self.rotation).0
+    }
+}
+
+impl<const W: usize, const H: usize, const N: usize> Default for StaticFramebuffer<W, H, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const H: usize, const N: usize> PixelDraw for StaticFramebuffer<W, H, N> {
+    fn draw_pixel(&mut self, x: i32, y: i32, color: &Color) -> bool {
+        let (x, y) = match self.to_physical(x, y) {
+            Some(coordinates) => coordinates,
+            None => return false,
+        };
+
+        write_pixel(&mut self.frame, W as i32, x, y, color);
+
+        true
+    }
+}
+
+impl<const W: usize, const H: usize, const N: usize> PixelRead for StaticFramebuffer<W, H, N> {
+    fn get_pixel(&self, x: i32, y: i32) -> Color {
+        StaticFramebuffer::get_pixel(self, x, y)
+    }
+}
+
+/// Number of bytes `Backup` needs to save an area of the given size in pixels.
+pub const fn backup_size(width: usize, height: usize) -> usize {
+    (width * height).div_ceil(8)
+}
+
+/// Copy of a part of the framebuffer, e.g. of the area under a dialog, to put it
+/// back once the dialog is closed. Holds up to `N * 8` pixels.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::{backup_size, Backup, Color, Framebuffer, PixelDraw};
+/// # let mut buffer = [0; 128];
+/// # let mut framebuffer = Framebuffer::new(128, 8, &mut buffer).unwrap();
+/// let mut backup = Backup::<{ backup_size(16, 8) }>::new();
+/// framebuffer.draw_pixel(0, 0, &Color::Black);
+///
+/// assert!(backup.save(&framebuffer, BBox::new((0, 0), (15, 7))));
+/// framebuffer.draw_pixel(0, 0, &Color::White);
+/// assert!(backup.restore(&mut framebuffer));
+/// assert_eq!(framebuffer.get_pixel(0, 0), Color::Black);
+/// ```
+pub struct Backup<const N: usize> {
+    bbox: Option<BBox>,
+    pixels: [u8; N],
+}
+
+impl<const N: usize> Default for Backup<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Backup<N> {
+    pub const fn new() -> Self {
+        Self {
+            bbox: None,
+            pixels: [0; N],
+        }
+    }
+
+    /// Area which is saved and not yet restored.
+    pub fn bbox(&self) -> Option<BBox> {
+        self.bbox
+    }
+
+    /// Save the pixels within the bounding box of any readable canvas, e.g. of the back
+    /// buffer of a `DoubleBuffer`. Returns `false` if the area doesn't fit, then whatever
+    /// is under it has to be redrawn instead.
+    pub fn save<R: PixelRead>(&mut self, framebuffer: &R, bbox: BBox) -> bool {
+        let width = bbox.end.0 - bbox.start.0 + 1;
+        let height = bbox.end.1 - bbox.start.1 + 1;
+        if width <= 0 || height <= 0 || backup_size(width as usize, height as usize) > N {
+            self.bbox = None;
+            return false;
+        }
+
+        for (index, (x, y)) in Self::pixels(bbox).enumerate() {
+            let mask = 1 << (index % 8);
+            match framebuffer.get_pixel(x, y) {
+                Color::Black => self.pixels[index / 8] |= mask,
+                Color::White => self.pixels[index / 8] &= !mask,
+            }
+        }
+        self.bbox = Some(bbox);
+
+        true
+    }
+
+    /// Put the saved pixels back. Returns `false` if nothing was saved.
+    pub fn restore<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        let bbox = match self.bbox.take() {
+            Some(bbox) => bbox,
+            None => return false,
+        };
+
+        for (index, (x, y)) in Self::pixels(bbox).enumerate() {
+            let color = if self.pixels[index / 8] & (1 << (index % 8)) != 0 {
+                Color::Black
+            } else {
+                Color::White
+            };
+            canvas.draw_pixel(x, y, &color);
+        }
+
+        true
+    }
+
+    /// Coordinates of the area row by row.
+    fn pixels(bbox: BBox) -> impl Iterator<Item = (i32, i32)> {
+        (bbox.start.1..=bbox.end.1)
+            .flat_map(move |y| (bbox.start.0..=bbox.end.0).map(move |x| (x, y)))
     }
 }
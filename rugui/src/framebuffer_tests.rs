@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::bounding_box::BBox;
+    use crate::framebuffer::{backup_size, Backup};
+    use crate::framebuffer::{buffer_size, Color, Framebuffer, PixelDraw, Rotation};
+    use crate::static_framebuffer;
+
+    #[test]
+    fn test_buffer_size() {
+        assert_eq!(buffer_size(128, 64), 1024);
+        assert_eq!(buffer_size(10, 12), 20);
+
+        let mut buffer = [0; 19];
+        assert!(Framebuffer::new(10, 12, &mut buffer).is_none());
+        let mut buffer = [0; 20];
+        let mut framebuffer = Framebuffer::new(10, 12, &mut buffer).unwrap();
+        assert!(framebuffer.draw_pixel(9, 11, &Color::Black));
+    }
+
+    #[test]
+    fn test_static_framebuffer() {
+        let mut display = <static_framebuffer!(10, 12)>::new();
+        assert!(display.draw_pixel(9, 11, &Color::Black));
+        assert!(!display.draw_pixel(10, 0, &Color::Black));
+        assert_eq!(display.get_pixel(9, 11), Color::Black);
+        assert_eq!(display.framebuffer().get_pixel(9, 11), Color::Black);
+
+        // Rotation is kept between borrows
+        display.set_rotation(Rotation::Deg90);
+        assert_eq!((display.get_width(), display.get_height()), (12, 10));
+        assert_eq!(display.get_pixel(11, 0), Color::Black);
+        assert!(display.draw_pixel(0, 0, &Color::Black));
+        assert_eq!(display.framebuffer().get_rotation(), Rotation::Deg90);
+        assert_eq!(display.framebuffer().get_pixel(0, 0), Color::Black);
+
+        display.set_rotation(Rotation::Deg0);
+        assert_eq!(display.get_pixel(9, 0), Color::Black);
+    }
+
+    #[test]
+    fn test_clipping() {
+        let mut buffer = [0; 8];
+        let mut framebuffer = Framebuffer::new(8, 8, &mut buffer).unwrap();
+        assert!(!framebuffer.draw_pixel(-1, 0, &Color::Black));
+        assert!(!framebuffer.draw_pixel(0, -1, &Color::Black));
+        assert!(!framebuffer.draw_pixel(8, 0, &Color::Black));
+        assert!(framebuffer.draw_pixel(7, 7, &Color::Black));
+        assert_eq!(framebuffer.get_pixel(8, 8), Color::White);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let cases = [
+            (Rotation::Deg0, false, (1, 0)),
+            (Rotation::Deg90, false, (7, 1)),
+            (Rotation::Deg180, false, (6, 7)),
+            (Rotation::Deg270, false, (0, 6)),
+            (Rotation::Deg0, true, (6, 0)),
+            (Rotation::Deg90, true, (7, 6)),
+        ];
+
+        for (rotation, mirrored, physical) in cases {
+            let mut buffer = [0; 8];
+            let mut framebuffer = Framebuffer::new(8, 8, &mut buffer)
+                .unwrap()
+                .rotation(rotation)
+                .mirrored(mirrored);
+            framebuffer.draw_pixel(1, 0, &Color::Black);
+            assert_eq!(framebuffer.get_pixel(1, 0), Color::Black);
+
+            let framebuffer = Framebuffer::new(8, 8, &mut buffer).unwrap();
+            assert_eq!(
+                framebuffer.get_pixel(physical.0, physical.1),
+                Color::Black,
+                "{:?} mirrored: {}",
+                rotation,
+                mirrored
+            );
+        }
+    }
+
+    #[test]
+    fn test_logical_size() {
+        let mut buffer = [0; 32];
+        let mut framebuffer = Framebuffer::new(32, 8, &mut buffer)
+            .unwrap()
+            .rotation(Rotation::Deg90);
+        assert_eq!(framebuffer.get_width(), 8);
+        assert_eq!(framebuffer.get_height(), 32);
+        assert!(framebuffer.draw_pixel(7, 31, &Color::Black));
+        assert!(!framebuffer.draw_pixel(8, 0, &Color::Black));
+        assert!(!framebuffer.draw_pixel(0, 32, &Color::Black));
+    }
+
+    #[test]
+    fn test_backup() {
+        let mut buffer = [0; 16];
+        let mut framebuffer = Framebuffer::new(16, 8, &mut buffer).unwrap();
+        framebuffer.draw_pixel(2, 1, &Color::Black);
+        framebuffer.draw_pixel(4, 3, &Color::Black);
+
+        let mut small = Backup::<1>::new();
+        assert!(!small.save(&framebuffer, BBox::new((0, 0), (2, 2))));
+        assert!(!small.restore(&mut framebuffer));
+
+        let mut backup = Backup::<{ backup_size(3, 3) }>::new();
+        assert!(backup.save(&framebuffer, BBox::new((2, 1), (4, 3))));
+        for x in 0..16 {
+            for y in 0..8 {
+                framebuffer.draw_pixel(x, y, &Color::Black);
+            }
+        }
+
+        // Only the saved area is restored, once
+        assert!(backup.restore(&mut framebuffer));
+        assert!(backup.bbox().is_none());
+        assert_eq!(framebuffer.get_pixel(2, 1), Color::Black);
+        assert_eq!(framebuffer.get_pixel(3, 1), Color::White);
+        assert_eq!(framebuffer.get_pixel(3, 2), Color::White);
+        assert_eq!(framebuffer.get_pixel(4, 3), Color::Black);
+        assert_eq!(framebuffer.get_pixel(5, 3), Color::Black);
+        assert!(!backup.restore(&mut framebuffer));
+
+        // Any readable canvas can be saved
+        let mut display = <static_framebuffer!(16, 8)>::new();
+        display.draw_pixel(3, 2, &Color::Black);
+        assert!(backup.save(&display, BBox::new((2, 1), (4, 3))));
+        display.draw_pixel(3, 2, &Color::White);
+        assert!(backup.restore(&mut display));
+        assert_eq!(display.get_pixel(3, 2), Color::Black);
+    }
+}
@@ -1,12 +1,48 @@
 use super::coordinates::bounding_box::*;
 use super::framebuffer::PixelDraw;
 use super::framebuffer::*;
-use crate::coordinates::cvec::Vec2;
+use crate::coordinates::cvec::{Point, Vec2};
+use crate::fixed::Fixed;
+use crate::transform::{Resampled, Transform, Transformable};
 
 pub trait Drawable {
     fn draw<C: PixelDraw>(&self, canvas: &mut C);
 }
 
+impl<T: Drawable> Drawable for [T] {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        for item in self {
+            item.draw(canvas);
+        }
+    }
+}
+
+impl<T: Drawable, const N: usize> Drawable for [T; N] {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        self[..].draw(canvas);
+    }
+}
+
+/// Draw a group of different drawables, e.g. a whole screen.
+macro_rules! impl_drawable_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Drawable),+> Drawable for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+                let ($($name,)+) = self;
+                $($name.draw(canvas);)+
+            }
+        }
+    };
+}
+
+impl_drawable_tuple!(A, B);
+impl_drawable_tuple!(A, B, D);
+impl_drawable_tuple!(A, B, D, E);
+impl_drawable_tuple!(A, B, D, E, F);
+impl_drawable_tuple!(A, B, D, E, F, G);
+
+/// Straight line from `bbox.start` to `bbox.end`, in any direction.
 pub struct Line {
     bbox: BBox,
     color: Color,
@@ -27,6 +63,24 @@ pub struct Circle {
     color: Color,
 }
 
+/// Monochrome image. Each row starts at a new byte, most significant bit is the leftmost pixel.
+/// Only set bits are drawn, so the background stays intact.
+#[derive(Clone, Copy)]
+pub struct Bitmap<'a> {
+    position: Vec2,
+    width: u32,
+    height: u32,
+    data: &'a [u8],
+    color: Color,
+}
+
+/// Closed polygon through the points. Filled polygons use the even-odd rule.
+pub struct Polygon<'a> {
+    points: &'a [Vec2],
+    color: Color,
+    filled: bool,
+}
+
 pub struct Ellipse {
     center: Vec2,
     height: u32,
@@ -63,27 +117,34 @@ impl Drawable for Line {
             return;
         }
 
-        let bbox = self.bbox;
-        let color = self.color;
-
-        let mut delta = 2 * bbox.height() as i32 - bbox.width() as i32;
-        let mut y = bbox.start.1;
-
-        if bbox.width() == 0 {
-            for y in bbox.iter_y() {
-                canvas.draw_pixel(bbox.start.0, y, &color);
+        let (x0, y0) = self.bbox.start;
+        let (x1, y1) = self.bbox.end;
+        let (dx, dy) = ((x1 - x0).abs(), (y1 - y0).abs());
+        let (sx, sy) = ((x1 - x0).signum(), (y1 - y0).signum());
+
+        // Bresenham's algorithm, stepping along the longer axis
+        if dx >= dy {
+            let mut delta = 2 * dy - dx;
+            let mut y = y0;
+            for x in (0..=dx).map(|i| x0 + i * sx) {
+                canvas.draw_pixel(x, y, &self.color);
+                if delta > 0 {
+                    y += sy;
+                    delta -= 2 * dx;
+                }
+                delta += 2 * dy;
             }
-
-            return;
-        }
-
-        for x in bbox.iter_x() {
-            canvas.draw_pixel(x, y, &color);
-            if delta > 0 {
-                y += 1;
-                delta -= 2 * bbox.width() as i32;
+        } else {
+            let mut delta = 2 * dx - dy;
+            let mut x = x0;
+            for y in (0..=dy).map(|i| y0 + i * sy) {
+                canvas.draw_pixel(x, y, &self.color);
+                if delta > 0 {
+                    x += sx;
+                    delta -= 2 * dy;
+                }
+                delta += 2 * dx;
             }
-            delta += 2 * bbox.height() as i32;
         }
     }
 }
@@ -268,3 +329,259 @@ impl Drawable for Ellipse {
         }
     }
 }
+
+impl<'a> Bitmap<'a> {
+    pub const fn new(width: u32, height: u32, data: &'a [u8], color: Color) -> Self {
+        Self {
+            position: (0, 0),
+            width,
+            height,
+            data,
+            color,
+        }
+    }
+
+    /// Top left corner of the image.
+    pub fn at(mut self, position: Vec2) -> Self {
+        self.position = position;
+
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+
+        self
+    }
+
+    pub fn size(&self) -> Vec2 {
+        (self.width as i32, self.height as i32)
+    }
+
+    /// Is the pixel of the image set? Pixels outside of the image are not.
+    pub fn get_pixel(&self, x: u32, y: u32) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+
+        let stride = self.width.div_ceil(8);
+        let byte = self.data.get((y * stride + x / 8) as usize).copied();
+
+        byte.unwrap_or(0) & (0x80 >> (x % 8)) != 0
+    }
+}
+
+impl<'a> Drawable for Bitmap<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let (x, y) = self.position;
+
+        for dy in 0..self.height {
+            for dx in 0..self.width {
+                if self.get_pixel(dx, dy) {
+                    canvas.draw_pixel(x + dx as i32, y + dy as i32, &self.color);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Polygon<'a> {
+    pub fn new(points: &'a [Vec2], color: Color) -> Self {
+        Self {
+            points,
+            color,
+            filled: false,
+        }
+    }
+
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+
+        self
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + 'a {
+        let points = self.points;
+        let next = points.iter().cycle().skip(1);
+
+        points.iter().copied().zip(next.copied())
+    }
+}
+
+/// Horizontal position where the edge crosses the row, `None` if it doesn't. The lower
+/// end of the edge is excluded, so that a vertex joining two edges is counted once.
+fn crossing(((x0, y0), (x1, y1)): (Vec2, Vec2), y: i32) -> Option<i32> {
+    if y0 == y1 || y < y0.min(y1) || y >= y0.max(y1) {
+        return None;
+    }
+
+    Some(x0 + (y - y0) * (x1 - x0) / (y1 - y0))
+}
+
+impl<'a> Drawable for Polygon<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        if self.filled {
+            let top = self.points.iter().map(|point| point.1).min().unwrap_or(0);
+            let bottom = self.points.iter().map(|point| point.1).max().unwrap_or(-1);
+
+            for y in top..=bottom {
+                // Crossings are visited from left to right, ordered by the position and then
+                // by the edge, without storing them. Every pair of them encloses a span.
+                let mut previous = None;
+                let mut span_start = None;
+
+                while let Some(next) = self
+                    .edges()
+                    .enumerate()
+                    .filter_map(|(edge, points)| Some((crossing(points, y)?, edge)))
+                    .filter(|key| Some(*key) > previous)
+                    .min()
+                {
+                    match span_start.take() {
+                        None => span_start = Some(next.0),
+                        Some(start) => {
+                            for x in start..=next.0 {
+                                canvas.draw_pixel(x, y, &self.color);
+                            }
+                        }
+                    }
+                    previous = Some(next);
+                }
+            }
+        }
+
+        // Filled polygons are outlined too, so that rows of the horizontal edges are drawn
+        for (start, end) in self.edges() {
+            Line::new(BBox::new(start, end), self.color).draw(canvas);
+        }
+    }
+}
+
+/// Maximal number of points of a polygon which is transformed exactly,
+/// bigger polygons are resampled.
+const MAX_TRANSFORMED_POINTS: usize = 32;
+
+/// Is the transform coefficient zero, but for rounding?
+fn negligible(value: Fixed) -> bool {
+    value.abs() <= Fixed::from_bits(4)
+}
+
+impl Transformable for Line {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        let end = if self.vertical {
+            (self.bbox.start.0, self.bbox.end.1)
+        } else {
+            self.bbox.end
+        };
+        let middle = transform.map_middle(self.bbox.start, end);
+        let start = transform.apply_corner(self.bbox.start, middle);
+        let bbox = BBox::new(start, transform.apply_corner(end, middle));
+
+        Line::new(bbox, self.color).draw(canvas);
+    }
+}
+
+impl Transformable for Rect {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        let (start, end) = (self.bbox.start, self.bbox.end);
+        let corners = [start, (end.0, start.1), end, (start.0, end.1)];
+        let middle = transform.map_middle(start, end);
+        let corners = corners.map(|corner| transform.apply_corner(corner, middle));
+
+        Polygon::new(&corners, self.color)
+            .filled(self.filled)
+            .draw(canvas);
+    }
+}
+
+impl<'a> Transformable for Polygon<'a> {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        if self.points.len() > MAX_TRANSFORMED_POINTS {
+            self.draw(&mut Resampled::new(canvas, *transform));
+            return;
+        }
+
+        let points = self.points.iter().map(|point| Point::from(*point));
+        let start = points
+            .clone()
+            .fold(Point::new(i32::MAX, i32::MAX), Point::min);
+        let end = points.fold(Point::new(i32::MIN, i32::MIN), Point::max);
+        let middle = transform.map_middle(start.into(), end.into());
+
+        let mut points = [(0, 0); MAX_TRANSFORMED_POINTS];
+        for (point, source) in points.iter_mut().zip(self.points) {
+            *point = transform.apply_corner(*source, middle);
+        }
+
+        Polygon::new(&points[..self.points.len()], self.color)
+            .filled(self.filled)
+            .draw(canvas);
+    }
+}
+
+/// Radius of a round shape of `2 * r + 1` pixels across, after scaling. The scaled shape
+/// spans as many pixels as the resampled one, less one if that count is even.
+fn radius(r: u32, scale: Fixed) -> u32 {
+    ((scale * (2 * r as i32 + 1) / 2).ceil() - 1).max(0) as u32
+}
+
+/// Circles stay circles if the transform only rotates, flips and scales evenly.
+impl Transformable for Circle {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        let Transform { xx, xy, yx, yy, .. } = *transform;
+        let rotated = negligible(xx - yy) && negligible(xy + yx);
+        let mirrored = negligible(xx + yy) && negligible(xy - yx);
+        if !rotated && !mirrored {
+            self.draw(&mut Resampled::new(canvas, *transform));
+            return;
+        }
+
+        let scale = (xx * xx + xy * xy).sqrt();
+        let r = radius(self.r, scale).max(1);
+        let thickness = if self.thickness >= self.r {
+            r
+        } else {
+            ((scale * self.thickness as i32).round().max(1) as u32).min(r)
+        };
+
+        Circle {
+            center: transform.apply(self.center),
+            r,
+            thickness,
+            color: self.color,
+        }
+        .draw(canvas);
+    }
+}
+
+/// Ellipses stay ellipses if the transform turns them by quarters, flips or scales.
+impl Transformable for Ellipse {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        let Transform { xx, xy, yx, yy, .. } = *transform;
+        let (sx, sy, swapped) = if negligible(xy) && negligible(yx) {
+            (xx.abs(), yy.abs(), false)
+        } else if negligible(xx) && negligible(yy) {
+            (yx.abs(), xy.abs(), true)
+        } else {
+            self.draw(&mut Resampled::new(canvas, *transform));
+            return;
+        };
+
+        let (width, height) = match swapped {
+            false => (radius(self.width, sx), radius(self.height, sy)),
+            true => (radius(self.height, sy), radius(self.width, sx)),
+        };
+        let ellipse = Ellipse::new(width, height, transform.apply(self.center), self.color);
+        let thickness = if self.thickness >= self.max_thickness() {
+            ellipse.max_thickness()
+        } else {
+            let thickness = sx.min(sy) * self.thickness as i32;
+            thickness.round().max(1) as u32
+        };
+
+        ellipse.thickness(thickness).draw(canvas);
+    }
+}
+
+/// Bitmaps are resampled by the nearest neighbour.
+impl<'a> Transformable for Bitmap<'a> {}
@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod geometry_tests {
     use crate::coordinates::bounding_box::*;
-    use crate::framebuffer::Color;
-    use crate::geometry::{Circle, Ellipse};
+    use crate::framebuffer::{Color, Framebuffer};
+    use crate::geometry::{Circle, Drawable, Ellipse, Line, Polygon};
 
     #[test]
     fn test_iter() {
@@ -33,4 +33,100 @@ mod geometry_tests {
         let ellipse = Ellipse::new(123, 100, (10, 10), Color::Black);
         assert_eq!(ellipse.max_thickness(), 100);
     }
+
+    #[test]
+    fn test_line_directions() {
+        let mut buffer = [0; 8];
+        let mut framebuffer = Framebuffer::new(8, 8, &mut buffer).unwrap();
+
+        Line::new(BBox::new((7, 0), (0, 3)), Color::Black).draw(&mut framebuffer);
+        Line::new(BBox::new((0, 7), (2, 0)), Color::Black).draw(&mut framebuffer);
+
+        let lit = |x, y| framebuffer.get_pixel(x, y) == Color::Black;
+        assert!(lit(7, 0) && lit(0, 3) && lit(4, 1));
+        assert!(lit(0, 7) && lit(2, 0) && lit(1, 4));
+        assert!(!lit(7, 7));
+    }
+
+    /// Columns of an 8x8 frame, one bit per row.
+    fn draw_line(bbox: BBox) -> [u8; 8] {
+        let mut buffer = [0; 8];
+        let mut framebuffer = Framebuffer::new(8, 8, &mut buffer).unwrap();
+        Line::new(bbox, Color::Black).draw(&mut framebuffer);
+
+        buffer
+    }
+
+    #[test]
+    fn test_line_pixels() {
+        // Both directions light the same pixels, two per row
+        let expected = [1, 1, 2, 2, 4, 4, 8, 8];
+        assert_eq!(draw_line(BBox::new((0, 0), (7, 3))), expected);
+        assert_eq!(draw_line(BBox::new((7, 3), (0, 0))), expected);
+
+        // Steep lines have one pixel per row
+        let columns = draw_line(BBox::new((1, 7), (4, 0)));
+        assert_eq!(columns.iter().fold(0, |rows, column| rows ^ column), 0xFF);
+        assert_eq!(
+            columns
+                .iter()
+                .map(|column| column.count_ones())
+                .sum::<u32>(),
+            8
+        );
+
+        assert_eq!(
+            draw_line(BBox::new((3, 3), (3, 3))),
+            [0, 0, 0, 8, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            draw_line(BBox::new((1, 5), (6, 5))),
+            [0, 32, 32, 32, 32, 32, 32, 0]
+        );
+    }
+
+    #[test]
+    fn test_polygon() {
+        let mut buffer = [0; 16];
+        let mut framebuffer = Framebuffer::new(16, 8, &mut buffer).unwrap();
+        let triangle = [(0, 0), (14, 0), (0, 7)];
+
+        Polygon::new(&triangle, Color::Black).draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(7, 3), Color::Black);
+        assert_eq!(framebuffer.get_pixel(3, 2), Color::White);
+
+        Polygon::new(&triangle, Color::Black)
+            .filled(true)
+            .draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(3, 2), Color::Black);
+        assert_eq!(framebuffer.get_pixel(12, 5), Color::White);
+    }
+
+    #[test]
+    fn test_polygon_many_crossings() {
+        let mut buffer = [0; 40];
+        let mut framebuffer = Framebuffer::new(40, 8, &mut buffer).unwrap();
+
+        // Comb with 10 teeth, so 20 edges cross the upper rows
+        let mut comb = [(0, 7); 42];
+        for tooth in 0..10 {
+            let x = tooth as i32 * 4;
+            comb[tooth * 4 + 1..tooth * 4 + 5].copy_from_slice(&[
+                (x, 0),
+                (x + 1, 0),
+                (x + 1, 4),
+                (x + 3, 4),
+            ]);
+        }
+        comb[41] = (39, 7);
+
+        Polygon::new(&comb, Color::Black)
+            .filled(true)
+            .draw(&mut framebuffer);
+        for tooth in 0..10 {
+            assert_eq!(framebuffer.get_pixel(tooth * 4 + 1, 2), Color::Black);
+            assert_eq!(framebuffer.get_pixel(tooth * 4 + 2, 2), Color::White);
+        }
+        assert_eq!(framebuffer.get_pixel(20, 6), Color::Black);
+    }
 }
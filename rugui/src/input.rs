@@ -0,0 +1,254 @@
+use crate::coordinates::cvec::Point;
+use crate::widgets::WidgetList;
+
+/// Navigation keys found on devices without touch screens.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Back,
+    /// Push button of a rotary encoder
+    EncoderPush,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Event {
+    KeyPress(Key),
+    KeyRelease(Key),
+    /// Key is held for a long time. Sent once, before the first `KeyRepeat`.
+    KeyLongPress(Key),
+    /// Key is still held, sent periodically after `KeyLongPress`.
+    KeyRepeat(Key),
+    /// Rotary encoder steps. Positive value means clockwise rotation.
+    Encoder(i8),
+    TouchDown(Point),
+    TouchMove(Point),
+    TouchUp(Point),
+}
+
+impl Event {
+    /// Coordinates of touch events. Such events are routed by position, the rest go to
+    /// the focused widget.
+    pub fn position(&self) -> Option<Point> {
+        match self {
+            Event::TouchDown(point) | Event::TouchMove(point) | Event::TouchUp(point) => {
+                Some(*point)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Deliver the event to a widget. Touch events go to the first widget under the point,
+/// others go to the `focused` one. Returns the index of the widget which consumed the event.
+pub fn dispatch<L: WidgetList + ?Sized>(
+    widgets: &mut L,
+    focused: Option<usize>,
+    event: &Event,
+) -> Option<usize> {
+    let target = match event.position() {
+        Some(point) => widgets.position_at(point),
+        None => focused.filter(|&i| i < widgets.len()),
+    }?;
+
+    if widgets.handle_event(target, event) {
+        Some(target)
+    } else {
+        None
+    }
+}
+
+/// Filters contact bounce of a mechanical switch.
+/// The state is accepted after it didn't change for `delay` milliseconds.
+pub struct Debouncer {
+    delay: u32,
+    stable: bool,
+    raw: bool,
+    changed_at: u32,
+}
+
+impl Debouncer {
+    pub const fn new(delay: u32) -> Self {
+        Self {
+            delay,
+            stable: false,
+            raw: false,
+            changed_at: 0,
+        }
+    }
+
+    /// Feed a raw switch state with the current time in milliseconds.
+    /// Returns the new state when the debounced state changes.
+    pub fn update(&mut self, raw: bool, now: u32) -> Option<bool> {
+        if raw != self.raw {
+            self.raw = raw;
+            self.changed_at = now;
+        }
+
+        if self.raw != self.stable && now.wrapping_sub(self.changed_at) >= self.delay {
+            self.stable = self.raw;
+            return Some(self.stable);
+        }
+
+        None
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.stable
+    }
+}
+
+/// Turns raw state of a single key into debounced press, release, long press and repeat events.
+///
+/// ```
+/// # use rugui::input::{Event, Key, KeyInput};
+/// let mut key = KeyInput::new(Key::Enter).debounce(10).long_press(500).repeat(100);
+///
+/// assert_eq!(key.update(true, 0), None);
+/// assert_eq!(key.update(true, 10), Some(Event::KeyPress(Key::Enter)));
+/// assert_eq!(key.update(true, 510), Some(Event::KeyLongPress(Key::Enter)));
+/// assert_eq!(key.update(true, 610), Some(Event::KeyRepeat(Key::Enter)));
+/// ```
+pub struct KeyInput {
+    key: Key,
+    debouncer: Debouncer,
+    long_press: u32,
+    repeat: u32,
+    /// Time of the press or of the last generated event while the key is held
+    last_event: u32,
+    long_pressed: bool,
+}
+
+impl KeyInput {
+    /// Defaults are 20 ms debounce, 800 ms long press and 200 ms repeat interval.
+    pub const fn new(key: Key) -> Self {
+        Self {
+            key,
+            debouncer: Debouncer::new(20),
+            long_press: 800,
+            repeat: 200,
+            last_event: 0,
+            long_pressed: false,
+        }
+    }
+
+    pub const fn debounce(mut self, delay: u32) -> Self {
+        self.debouncer = Debouncer::new(delay);
+
+        self
+    }
+
+    /// Time to hold the key before `KeyLongPress`. Zero disables long press and repeat.
+    pub const fn long_press(mut self, delay: u32) -> Self {
+        self.long_press = delay;
+
+        self
+    }
+
+    /// Interval of `KeyRepeat` events. Zero disables repeat.
+    pub const fn repeat(mut self, interval: u32) -> Self {
+        self.repeat = interval;
+
+        self
+    }
+
+    /// Feed a raw key state with the current time in milliseconds.
+    pub fn update(&mut self, raw: bool, now: u32) -> Option<Event> {
+        match self.debouncer.update(raw, now) {
+            Some(true) => {
+                self.last_event = now;
+                self.long_pressed = false;
+                return Some(Event::KeyPress(self.key));
+            }
+            Some(false) => return Some(Event::KeyRelease(self.key)),
+            None => {}
+        }
+
+        if !self.debouncer.is_pressed() || self.long_press == 0 {
+            return None;
+        }
+
+        let held = now.wrapping_sub(self.last_event);
+        if !self.long_pressed {
+            if held >= self.long_press {
+                self.long_pressed = true;
+                self.last_event = now;
+                return Some(Event::KeyLongPress(self.key));
+            }
+        } else if self.repeat > 0 && held >= self.repeat {
+            self.last_event = now;
+            return Some(Event::KeyRepeat(self.key));
+        }
+
+        None
+    }
+}
+
+/// Decodes quadrature signals of a rotary encoder into `Event::Encoder` steps.
+pub struct EncoderInput {
+    state: u8,
+    counts: i8,
+    counts_per_step: i8,
+}
+
+impl EncoderInput {
+    /// Most encoders with detents produce 4 state changes per step.
+    /// Values below 1 are treated as 1.
+    pub const fn new(counts_per_step: i8) -> Self {
+        Self {
+            state: 0,
+            counts: 0,
+            counts_per_step: if counts_per_step < 1 {
+                1
+            } else {
+                counts_per_step
+            },
+        }
+    }
+
+    /// Feed the current levels of `A` and `B` encoder outputs.
+    pub fn update(&mut self, a: bool, b: bool) -> Option<Event> {
+        // Gray code sequence for the clockwise rotation: 00 -> 01 -> 11 -> 10
+        const TRANSITIONS: [i8; 16] = [0, 1, -1, 0, -1, 0, 0, 1, 1, 0, 0, -1, 0, -1, 1, 0];
+
+        let state = (a as u8) << 1 | b as u8;
+        self.counts += TRANSITIONS[(self.state << 2 | state) as usize];
+        self.state = state;
+
+        if self.counts.abs() >= self.counts_per_step {
+            let steps = self.counts / self.counts_per_step;
+            self.counts %= self.counts_per_step;
+            return Some(Event::Encoder(steps));
+        }
+
+        None
+    }
+}
+
+/// Turns touch panel samples into touch down, move and up events.
+#[derive(Default)]
+pub struct TouchInput {
+    last: Option<Point>,
+}
+
+impl TouchInput {
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Feed the touch coordinates, or `None` if the panel isn't touched.
+    pub fn update(&mut self, sample: Option<Point>) -> Option<Event> {
+        let event = match (self.last, sample) {
+            (None, Some(point)) => Some(Event::TouchDown(point)),
+            (Some(last), Some(point)) if last != point => Some(Event::TouchMove(point)),
+            (Some(last), None) => Some(Event::TouchUp(last)),
+            _ => None,
+        };
+        self.last = sample;
+
+        event
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::bounding_box::BBox;
+    use crate::coordinates::cvec::Point;
+    use crate::framebuffer::Color;
+    use crate::input::*;
+    use crate::widgets::{Column, ColumnWidth, Table};
+
+    #[test]
+    fn test_debouncer() {
+        let mut debouncer = Debouncer::new(10);
+        assert_eq!(debouncer.update(true, 0), None);
+        assert_eq!(debouncer.update(false, 3), None);
+        assert_eq!(debouncer.update(true, 5), None);
+        assert_eq!(debouncer.update(true, 14), None);
+        assert_eq!(debouncer.update(true, 15), Some(true));
+        assert_eq!(debouncer.update(true, 100), None);
+        assert_eq!(debouncer.update(false, 101), None);
+        assert_eq!(debouncer.update(false, 111), Some(false));
+    }
+
+    #[test]
+    fn test_debouncer_tick_overflow() {
+        let mut debouncer = Debouncer::new(10);
+        debouncer.update(true, u32::MAX - 5);
+        assert_eq!(debouncer.update(true, 4), Some(true));
+    }
+
+    #[test]
+    fn test_key_input() {
+        let mut key = KeyInput::new(Key::Up)
+            .debounce(0)
+            .long_press(100)
+            .repeat(50);
+        assert_eq!(key.update(true, 0), Some(Event::KeyPress(Key::Up)));
+        assert_eq!(key.update(true, 99), None);
+        assert_eq!(key.update(true, 100), Some(Event::KeyLongPress(Key::Up)));
+        assert_eq!(key.update(true, 149), None);
+        assert_eq!(key.update(true, 150), Some(Event::KeyRepeat(Key::Up)));
+        assert_eq!(key.update(true, 200), Some(Event::KeyRepeat(Key::Up)));
+        assert_eq!(key.update(false, 210), Some(Event::KeyRelease(Key::Up)));
+        assert_eq!(key.update(false, 1000), None);
+
+        let mut key = KeyInput::new(Key::Up).debounce(0).long_press(0);
+        assert_eq!(key.update(true, 0), Some(Event::KeyPress(Key::Up)));
+        assert_eq!(key.update(true, 5000), None);
+    }
+
+    #[test]
+    fn test_encoder_input() {
+        let mut encoder = EncoderInput::new(4);
+        let clockwise = [(false, true), (true, true), (true, false)];
+        for (a, b) in clockwise {
+            assert_eq!(encoder.update(a, b), None);
+        }
+        assert_eq!(encoder.update(false, false), Some(Event::Encoder(1)));
+
+        let counterclockwise = [(true, false), (true, true), (false, true)];
+        for (a, b) in counterclockwise {
+            assert_eq!(encoder.update(a, b), None);
+        }
+        assert_eq!(encoder.update(false, false), Some(Event::Encoder(-1)));
+
+        // Every state change is a step
+        for counts_per_step in [0, -4] {
+            let mut encoder = EncoderInput::new(counts_per_step);
+            assert_eq!(encoder.update(false, true), Some(Event::Encoder(1)));
+            assert_eq!(encoder.update(false, false), Some(Event::Encoder(-1)));
+        }
+    }
+
+    #[test]
+    fn test_touch_input() {
+        let mut touch = TouchInput::new();
+        assert_eq!(touch.update(None), None);
+        assert_eq!(
+            touch.update(Some(Point::new(1, 2))),
+            Some(Event::TouchDown(Point::new(1, 2)))
+        );
+        assert_eq!(touch.update(Some(Point::new(1, 2))), None);
+        assert_eq!(
+            touch.update(Some(Point::new(3, 2))),
+            Some(Event::TouchMove(Point::new(3, 2)))
+        );
+        assert_eq!(touch.update(None), Some(Event::TouchUp(Point::new(3, 2))));
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+        let rows: [&[&str]; 2] = [&["0"], &["1"]];
+        let mut tables = [
+            Table::new(BBox::new((0, 0), (30, 40)), &columns, &rows, Color::Black),
+            Table::new(BBox::new((31, 0), (60, 40)), &columns, &rows, Color::Black),
+        ];
+
+        let down = Event::KeyPress(Key::Down);
+        assert_eq!(dispatch(&mut tables, Some(1), &down), Some(1));
+        assert_eq!(dispatch(&mut tables, None, &down), None);
+        assert_eq!(tables[0].get_selected(), None);
+        assert_eq!(tables[1].get_selected(), Some(0));
+
+        // Tables don't handle touch yet
+        assert_eq!(
+            dispatch(&mut tables, Some(1), &Event::TouchDown(Point::new(5, 5))),
+            None
+        );
+    }
+}
@@ -0,0 +1,825 @@
+//! Containers which place widgets by their size hints instead of absolute coordinates.
+//!
+//! Layouts are widgets themselves, so they can be nested. Children are placed when
+//! the layout is created and whenever its bounding box changes. Touch events go to the
+//! child under the point; focus is left to a `FocusChain` over the children. Children
+//! are a `WidgetList`, so a container can hold widgets of different types.
+
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Size, Vec2};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Rect};
+use crate::input::Event;
+use crate::widgets::{Widget, WidgetList};
+
+fn along(axis: Axis, (x, y): Vec2) -> i32 {
+    match axis {
+        Axis::X => x,
+        Axis::Y => y,
+    }
+}
+
+fn across(axis: Axis, (x, y): Vec2) -> i32 {
+    match axis {
+        Axis::X => y,
+        Axis::Y => x,
+    }
+}
+
+/// Vector from positions along and across the axis.
+fn oriented(axis: Axis, along: i32, across: i32) -> Vec2 {
+    match axis {
+        Axis::X => (along, across),
+        Axis::Y => (across, along),
+    }
+}
+
+/// Offset of an object of `length` pixels within `space` pixels.
+fn offset(alignment: Alignment, space: i32, length: i32) -> i32 {
+    match alignment {
+        Alignment::Start => 0,
+        Alignment::Center => (space - length) / 2,
+        Alignment::End => space - length,
+    }
+}
+
+/// Shares `space` pixels in proportion to weights which come one by one. The parts add
+/// up exactly once all the weights came.
+struct Share {
+    space: i32,
+    total: i32,
+    sum: i32,
+}
+
+impl Share {
+    fn new(space: i32, total: i32) -> Self {
+        Self {
+            space,
+            total,
+            sum: 0,
+        }
+    }
+
+    fn next(&mut self, weight: i32) -> i32 {
+        if self.total <= 0 {
+            return 0;
+        }
+
+        let before = self.space * self.sum / self.total;
+        self.sum += weight;
+        self.space * self.sum / self.total - before
+    }
+}
+
+/// Share `space` pixels in proportion to `weights`. The parts add up exactly.
+fn share<const N: usize>(space: i32, weights: [i32; N]) -> [i32; N] {
+    let mut share = Share::new(space, weights.iter().sum());
+
+    weights.map(|weight| share.next(weight))
+}
+
+/// Lengths of children along a stack of `space` pixels. Children get their preferred
+/// lengths if there is enough space, otherwise they are shrunk towards the minimal ones.
+struct Distribute {
+    fits: bool,
+    growth: Share,
+    used: i32,
+}
+
+impl Distribute {
+    /// `min` and `preferred` are the total lengths of the children.
+    fn new(space: i32, min: i32, preferred: i32) -> Self {
+        let fits = preferred <= space;
+        let extra = (space - min).max(0);
+
+        Self {
+            fits,
+            growth: Share::new(extra, preferred - min),
+            used: if fits { preferred } else { min + extra },
+        }
+    }
+
+    /// Length of the next child.
+    fn next(&mut self, min: i32, preferred: i32) -> i32 {
+        match self.fits {
+            true => preferred,
+            false => min + self.growth.next(preferred - min),
+        }
+    }
+}
+
+/// Minimal and preferred lengths of the child along the `axis`.
+fn hints<L: WidgetList + ?Sized>(children: &L, index: usize, axis: Axis) -> (i32, i32) {
+    let min = along(axis, children.min_size(index).into()) + 1;
+    let preferred = along(axis, children.preferred_size(index).into()) + 1;
+
+    (min, preferred.max(min))
+}
+
+/// Size of children placed along the `axis`, as (along, across) in pixels.
+fn stack_size(axis: Axis, sizes: impl Iterator<Item = Vec2>, spacing: i32) -> (i32, i32) {
+    let (mut length, mut thickness, mut count) = (0, 0, 0);
+    for size in sizes {
+        length += along(axis, size) + 1;
+        thickness = thickness.max(across(axis, size) + 1);
+        count += 1;
+    }
+
+    (length + spacing * (count - 1).max(0), thickness)
+}
+
+/// Place children one after another along the `axis`.
+fn place_stack<L: WidgetList + ?Sized>(
+    children: &mut L,
+    bbox: BBox,
+    axis: Axis,
+    spacing: i32,
+    justify: Alignment,
+    align: Option<Alignment>,
+) {
+    let space = along(axis, bbox.end) - along(axis, bbox.start) + 1;
+    let thickness = across(axis, bbox.end) - across(axis, bbox.start) + 1;
+    let gaps = spacing * children.len().saturating_sub(1) as i32;
+
+    let (mut min, mut preferred) = (0, 0);
+    for index in 0..children.len() {
+        let hints = hints(children, index, axis);
+        min += hints.0;
+        preferred += hints.1;
+    }
+    let mut lengths = Distribute::new(space - gaps, min, preferred);
+
+    let used = lengths.used + gaps;
+    let start = along(axis, bbox.start) + offset(justify, space, used).max(0);
+    let (_, mut rest) = bbox.split(axis, start);
+
+    for index in 0..children.len() {
+        let (min, preferred) = hints(children, index, axis);
+        let length = lengths.next(min, preferred);
+        let (cell, after) = rest.split(axis, along(axis, rest.start) + length);
+        rest = after.split(axis, along(axis, after.start) + spacing).1;
+
+        let cell = match align {
+            None => cell,
+            Some(alignment) => {
+                let preferred = across(axis, children.preferred_size(index).into()) + 1;
+                let width = preferred.min(thickness);
+                let start = across(axis, cell.start) + offset(alignment, thickness, width);
+                BBox::new(
+                    oriented(axis, along(axis, cell.start), start),
+                    oriented(axis, along(axis, cell.end), start + width - 1),
+                )
+            }
+        };
+        children.set_bbox(index, cell);
+    }
+}
+
+/// Deliver a touch event to the child under the point.
+fn route<L: WidgetList + ?Sized>(children: &mut L, event: &Event) -> bool {
+    match event.position() {
+        Some(point) => children
+            .position_at(point)
+            .is_some_and(|index| children.handle_event(index, event)),
+        None => false,
+    }
+}
+
+/// Draw all the children.
+fn draw_all<L: WidgetList + ?Sized, C: PixelDraw>(children: &L, canvas: &mut C) {
+    for index in 0..children.len() {
+        children.draw_widget(index, canvas);
+    }
+}
+
+/// Draw the changed children, returns `true` if any was drawn.
+fn redraw_all<L: WidgetList + ?Sized, C: PixelDraw>(children: &mut L, canvas: &mut C) -> bool {
+    let mut drawn = false;
+    for index in 0..children.len() {
+        drawn |= children.redraw_widget(index, canvas);
+    }
+
+    drawn
+}
+
+fn needs_redraw_any<L: WidgetList + ?Sized>(children: &L) -> bool {
+    (0..children.len()).any(|index| children.needs_redraw(index))
+}
+
+fn mark_all_drawn<L: WidgetList + ?Sized>(children: &mut L) {
+    for index in 0..children.len() {
+        children.mark_drawn(index);
+    }
+}
+
+macro_rules! stack {
+    ($(#[$meta:meta])* $name:ident, $axis:expr) => {
+        $(#[$meta])*
+        pub struct $name<L: WidgetList> {
+            bbox: BBox,
+            children: L,
+            spacing: i32,
+            justify: Alignment,
+            align: Option<Alignment>,
+            dirty: bool,
+        }
+
+        impl<L: WidgetList> $name<L> {
+            pub fn new(bbox: BBox, children: L) -> Self {
+                let mut stack = Self {
+                    bbox,
+                    children,
+                    spacing: 0,
+                    justify: Alignment::Start,
+                    align: None,
+                    dirty: true,
+                };
+                stack.layout();
+
+                stack
+            }
+
+            /// Pixels between neighbouring children.
+            pub fn spacing(mut self, spacing: i32) -> Self {
+                self.spacing = spacing;
+                self.layout();
+
+                self
+            }
+
+            /// Placement of the children along the stack if they don't take all the space.
+            pub fn justify(mut self, justify: Alignment) -> Self {
+                self.justify = justify;
+                self.layout();
+
+                self
+            }
+
+            /// Give children their preferred thickness instead of stretching them across
+            /// the stack, and align them this way.
+            pub fn align(mut self, align: Alignment) -> Self {
+                self.align = Some(align);
+                self.layout();
+
+                self
+            }
+
+            pub fn children(&self) -> &L {
+                &self.children
+            }
+
+            pub fn children_mut(&mut self) -> &mut L {
+                &mut self.children
+            }
+
+            /// Place the children again, e.g. after their size hints changed.
+            pub fn layout(&mut self) {
+                let (spacing, justify, align) = (self.spacing, self.justify, self.align);
+                place_stack(&mut self.children, self.bbox, $axis, spacing, justify, align);
+
+                self.dirty = true;
+            }
+        }
+
+        impl<L: WidgetList> Drawable for $name<L> {
+            fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+                draw_all(&self.children, &mut Clip::new(canvas, self.bbox));
+            }
+        }
+
+        impl<L: WidgetList> Widget for $name<L> {
+            fn min_size(&self) -> Size {
+                let children = 0..self.children.len();
+                let sizes = children.map(|i| self.children.min_size(i).into());
+                let (length, thickness) = stack_size($axis, sizes, self.spacing);
+
+                oriented($axis, length - 1, thickness - 1).into()
+            }
+
+            fn preferred_size(&self) -> Size {
+                let children = 0..self.children.len();
+                let sizes = children.map(|i| self.children.preferred_size(i).into());
+                let (length, thickness) = stack_size($axis, sizes, self.spacing);
+
+                oriented($axis, length - 1, thickness - 1).into()
+            }
+
+            fn bbox(&self) -> BBox {
+                self.bbox
+            }
+
+            fn set_bbox(&mut self, bbox: BBox) {
+                self.bbox = bbox;
+                self.layout();
+            }
+
+            fn handle_event(&mut self, event: &Event) -> bool {
+                route(&mut self.children, event)
+            }
+
+            fn needs_redraw(&self) -> bool {
+                self.dirty || needs_redraw_any(&self.children)
+            }
+
+            fn mark_drawn(&mut self) {
+                self.dirty = false;
+                mark_all_drawn(&mut self.children);
+            }
+
+            /// Only the changed children are drawn, unless the stack was laid out again.
+            fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+                if self.dirty {
+                    self.draw(canvas);
+                    self.mark_drawn();
+                    return true;
+                }
+
+                redraw_all(&mut self.children, &mut Clip::new(canvas, self.bbox))
+            }
+        }
+    };
+}
+
+stack!(
+    /// Children placed in a row from left to right.
+    ///
+    /// Children get their preferred widths, or less down to their minimal widths if the
+    /// row is too narrow, and are stretched to the height of the row unless aligned.
+    ///
+    /// ```
+    /// # use rugui::coordinates::bounding_box::BBox;
+    /// # use rugui::framebuffer::Color;
+    /// # use rugui::layout::HStack;
+    /// # use rugui::widgets::{Button, Widget};
+    /// let buttons = [
+    ///     Button::new(BBox::new((0, 0), (0, 0)), "OK", Color::Black),
+    ///     Button::new(BBox::new((0, 0), (0, 0)), "Cancel", Color::Black),
+    /// ];
+    /// let row = HStack::new(BBox::new((0, 50), (127, 63)), buttons).spacing(4);
+    ///
+    /// let ok = row.children()[0].bbox();
+    /// assert_eq!(row.children()[1].bbox().start.0, ok.end.0 + 5);
+    /// ```
+    HStack,
+    Axis::X
+);
+
+stack!(
+    /// Children placed in a column from top to bottom.
+    ///
+    /// Children get their preferred heights, or less down to their minimal heights if the
+    /// column is too short, and are stretched to the width of the column unless aligned.
+    ///
+    /// ```
+    /// # use rugui::coordinates::bounding_box::{Alignment, BBox};
+    /// # use rugui::framebuffer::Color;
+    /// # use rugui::layout::VStack;
+    /// # use rugui::widgets::{ProgressBar, Slider, Widget};
+    /// let zero = BBox::new((0, 0), (0, 0));
+    /// let children = (
+    ///     ProgressBar::new(zero, 10, 100, Color::Black),
+    ///     Slider::new(zero, 0, 100, 90, Color::Black),
+    /// );
+    /// let column = VStack::new(BBox::new((0, 0), (127, 63)), children)
+    ///     .spacing(2)
+    ///     .justify(Alignment::Center);
+    ///
+    /// assert_eq!(column.children().0.bbox().start, (0, 22));
+    /// assert_eq!(column.children().1.bbox().end, (127, 41));
+    /// ```
+    VStack,
+    Axis::Y
+);
+
+/// Size of a grid row or column.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Track {
+    /// Number of pixels.
+    Fixed(i32),
+    /// Share of the space left after fixed tracks, relative to other fractions.
+    Fraction(i32),
+}
+
+/// Lengths of tracks within `space` pixels, fractions share what is left.
+fn track_lengths<const N: usize>(space: i32, tracks: &[Track; N]) -> [i32; N] {
+    let fixed = tracks.map(|track| match track {
+        Track::Fixed(pixels) => pixels,
+        Track::Fraction(_) => 0,
+    });
+    let weights = tracks.map(|track| match track {
+        Track::Fixed(_) => 0,
+        Track::Fraction(weight) => weight,
+    });
+
+    let left = (space - fixed.iter().sum::<i32>()).max(0);
+    let shares = share(left, weights);
+
+    core::array::from_fn(|i| fixed[i] + shares[i])
+}
+
+/// Pixels needed by tracks to give each cell at least its `lengths`.
+fn tracks_length<const N: usize>(tracks: &[Track; N], lengths: [i32; N]) -> i32 {
+    let total: i32 = tracks
+        .iter()
+        .map(|track| match track {
+            Track::Fixed(_) => 0,
+            Track::Fraction(weight) => *weight,
+        })
+        .sum();
+
+    let mut fixed = 0;
+    let mut fractions = 0;
+    for (track, length) in tracks.iter().zip(lengths) {
+        match track {
+            Track::Fixed(pixels) => fixed += pixels,
+            Track::Fraction(weight) if *weight > 0 => {
+                // The whole fractional space must be big enough for this share
+                let needed = (length * total + weight - 1) / weight;
+                fractions = fractions.max(needed);
+            }
+            Track::Fraction(_) => {}
+        }
+    }
+
+    fixed + fractions
+}
+
+/// Children placed in cells of rows and columns.
+///
+/// Each child fills its cell. Tracks are either a fixed number of pixels or a fraction
+/// of the space left, so a grid can have e.g. a fixed label column and a value column
+/// taking the rest. Children are listed row by row, one per cell.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::layout::{Grid, Track};
+/// # use rugui::widgets::{ProgressBar, Widget};
+/// let bar = |value| ProgressBar::new(BBox::new((0, 0), (0, 0)), value, 100, Color::Black);
+/// let grid = Grid::new(
+///     BBox::new((0, 0), (127, 63)),
+///     [Track::Fraction(1), Track::Fraction(1)],
+///     [Track::Fixed(28), Track::Fraction(1)],
+///     [bar(10), bar(20), bar(30), bar(40)],
+/// );
+///
+/// assert_eq!(grid.cells()[3].bbox().start, (28, 32));
+/// assert_eq!(grid.cells()[3].bbox().end, (127, 63));
+/// ```
+pub struct Grid<L: WidgetList, const ROWS: usize, const COLUMNS: usize> {
+    bbox: BBox,
+    rows: [Track; ROWS],
+    columns: [Track; COLUMNS],
+    cells: L,
+    spacing: Vec2,
+    dirty: bool,
+}
+
+impl<L: WidgetList, const ROWS: usize, const COLUMNS: usize> Grid<L, ROWS, COLUMNS> {
+    /// `cells` are listed row by row. Cells without a child are left empty.
+    pub fn new(bbox: BBox, rows: [Track; ROWS], columns: [Track; COLUMNS], cells: L) -> Self {
+        let mut grid = Self {
+            bbox,
+            rows,
+            columns,
+            cells,
+            spacing: (0, 0),
+            dirty: true,
+        };
+        grid.layout();
+
+        grid
+    }
+
+    /// Pixels between neighbouring columns and rows.
+    pub fn spacing(mut self, spacing: Vec2) -> Self {
+        self.spacing = spacing;
+        self.layout();
+
+        self
+    }
+
+    /// Index of the child in the cell.
+    pub fn index(&self, row: usize, column: usize) -> usize {
+        row * COLUMNS + column
+    }
+
+    pub fn cells(&self) -> &L {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut L {
+        &mut self.cells
+    }
+
+    /// Place the children again, e.g. after their size hints changed.
+    pub fn layout(&mut self) {
+        let (bbox, (spacing_x, spacing_y)) = (self.bbox, self.spacing);
+        let gaps_x = spacing_x * COLUMNS.saturating_sub(1) as i32;
+        let gaps_y = spacing_y * ROWS.saturating_sub(1) as i32;
+        let widths = track_lengths(bbox.width() as i32 + 1 - gaps_x, &self.columns);
+        let heights = track_lengths(bbox.height() as i32 + 1 - gaps_y, &self.rows);
+
+        let (_, mut rows) = bbox.split(Axis::Y, bbox.start.1);
+        for (row, height) in heights.into_iter().enumerate() {
+            let (line, after) = rows.split(Axis::Y, rows.start.1 + height);
+            rows = after.split(Axis::Y, after.start.1 + spacing_y).1;
+
+            let mut cells = line;
+            for (column, width) in widths.into_iter().enumerate() {
+                let (bbox, after) = cells.split(Axis::X, cells.start.0 + width);
+                cells = after.split(Axis::X, after.start.0 + spacing_x).1;
+
+                let index = self.index(row, column);
+                if index < self.cells.len() {
+                    self.cells.set_bbox(index, bbox);
+                }
+            }
+        }
+
+        self.dirty = true;
+    }
+
+    /// Largest minimal size of the children in the cells, plus one pixel.
+    fn min_length(&self, cells: impl Iterator<Item = usize>, axis: Axis) -> i32 {
+        let cells = cells.filter(|&index| index < self.cells.len());
+        let lengths = cells.map(|index| along(axis, self.cells.min_size(index).into()) + 1);
+
+        lengths.max().unwrap_or(0)
+    }
+}
+
+impl<L, const ROWS: usize, const COLUMNS: usize> Drawable for Grid<L, ROWS, COLUMNS>
+where
+    L: WidgetList,
+{
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        draw_all(&self.cells, &mut Clip::new(canvas, self.bbox));
+    }
+}
+
+impl<L, const ROWS: usize, const COLUMNS: usize> Widget for Grid<L, ROWS, COLUMNS>
+where
+    L: WidgetList,
+{
+    fn min_size(&self) -> Size {
+        let widths = core::array::from_fn(|column| {
+            let cells = (0..ROWS).map(|row| self.index(row, column));
+            self.min_length(cells, Axis::X)
+        });
+        let heights = core::array::from_fn(|row| {
+            let cells = (0..COLUMNS).map(|column| self.index(row, column));
+            self.min_length(cells, Axis::Y)
+        });
+        let gaps_x = self.spacing.0 * COLUMNS.saturating_sub(1) as i32;
+        let gaps_y = self.spacing.1 * ROWS.saturating_sub(1) as i32;
+
+        Size::new(
+            tracks_length(&self.columns, widths) + gaps_x - 1,
+            tracks_length(&self.rows, heights) + gaps_y - 1,
+        )
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.layout();
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        route(&mut self.cells, event)
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty || needs_redraw_any(&self.cells)
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+        mark_all_drawn(&mut self.cells);
+    }
+
+    /// Only the changed cells are drawn, unless the grid was laid out again.
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        if self.dirty {
+            self.draw(canvas);
+            self.mark_drawn();
+            return true;
+        }
+
+        redraw_all(&mut self.cells, &mut Clip::new(canvas, self.bbox))
+    }
+}
+
+/// Space around a child, filled with the background color.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::layout::Padding;
+/// # use rugui::widgets::{Button, Widget};
+/// let button = Button::new(BBox::new((0, 0), (0, 0)), "OK", Color::Black);
+/// let padded = Padding::new(BBox::new((0, 0), (40, 20)), button, (4, 2), Color::Black);
+///
+/// assert_eq!(padded.child().bbox().start, (4, 2));
+/// assert_eq!(padded.child().bbox().end, (36, 18));
+/// ```
+pub struct Padding<W: Widget> {
+    bbox: BBox,
+    child: W,
+    padding: Edges,
+    color: Color,
+    dirty: bool,
+}
+
+impl<W: Widget> Padding<W> {
+    /// `padding` is the number of pixels for every edge, for the left and right and the
+    /// top and bottom edges as a tuple, or `Edges`.
+    pub fn new(bbox: BBox, child: W, padding: impl Into<Edges>, color: Color) -> Self {
+        let mut padded = Self {
+            bbox,
+            child,
+            padding: padding.into(),
+            color,
+            dirty: true,
+        };
+        padded.set_bbox(bbox);
+
+        padded
+    }
+
+    pub fn child(&self) -> &W {
+        &self.child
+    }
+
+    pub fn child_mut(&mut self) -> &mut W {
+        &mut self.child
+    }
+}
+
+impl<W: Widget> Drawable for Padding<W> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        Rect::new_filled(self.bbox, self.color.invert()).draw(canvas);
+        self.child.draw(&mut Clip::new(canvas, self.bbox));
+    }
+}
+
+impl<W: Widget> Widget for Padding<W> {
+    fn min_size(&self) -> Size {
+        let Size { width, height } = self.child.min_size();
+
+        let (x, y) = self.padding.size();
+
+        Size::new(width + x, height + y)
+    }
+
+    fn preferred_size(&self) -> Size {
+        let Size { width, height } = self.child.preferred_size();
+
+        let (x, y) = self.padding.size();
+
+        Size::new(width + x, height + y)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.child.set_bbox(bbox.inset(self.padding));
+        self.dirty = true;
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.child.is_focusable()
+    }
+
+    fn is_editable(&self) -> bool {
+        self.child.is_editable()
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.child.set_focus(focus);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.child.handle_event(event)
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty || self.child.needs_redraw()
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+        self.child.mark_drawn();
+    }
+
+    /// Only the child is drawn, unless the padding was placed again.
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        if self.dirty {
+            self.draw(canvas);
+            self.mark_drawn();
+            return true;
+        }
+
+        self.child.redraw(&mut Clip::new(canvas, self.bbox))
+    }
+}
+
+/// Empty space around a child, which is left as it is, e.g. to show the parent
+/// background between widgets.
+pub struct Margin<W: Widget> {
+    bbox: BBox,
+    child: W,
+    margin: Edges,
+}
+
+impl<W: Widget> Margin<W> {
+    /// `margin` is the number of pixels for every edge, for the left and right and the
+    /// top and bottom edges as a tuple, or `Edges`.
+    pub fn new(bbox: BBox, child: W, margin: impl Into<Edges>) -> Self {
+        let mut spaced = Self {
+            bbox,
+            child,
+            margin: margin.into(),
+        };
+        spaced.set_bbox(bbox);
+
+        spaced
+    }
+
+    pub fn child(&self) -> &W {
+        &self.child
+    }
+
+    pub fn child_mut(&mut self) -> &mut W {
+        &mut self.child
+    }
+}
+
+impl<W: Widget> Drawable for Margin<W> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        self.child.draw(canvas);
+    }
+}
+
+impl<W: Widget> Widget for Margin<W> {
+    fn min_size(&self) -> Size {
+        let Size { width, height } = self.child.min_size();
+
+        let (x, y) = self.margin.size();
+
+        Size::new(width + x, height + y)
+    }
+
+    fn preferred_size(&self) -> Size {
+        let Size { width, height } = self.child.preferred_size();
+
+        let (x, y) = self.margin.size();
+
+        Size::new(width + x, height + y)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.child.set_bbox(bbox.inset(self.margin));
+    }
+
+    fn is_focusable(&self) -> bool {
+        self.child.is_focusable()
+    }
+
+    fn is_editable(&self) -> bool {
+        self.child.is_editable()
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.child.set_focus(focus);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.child.handle_event(event)
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.child.needs_redraw()
+    }
+
+    fn mark_drawn(&mut self) {
+        self.child.mark_drawn();
+    }
+
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        self.child.redraw(canvas)
+    }
+}
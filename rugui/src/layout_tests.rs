@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::bounding_box::{Alignment, BBox};
+    use crate::coordinates::cvec::{Point, Size};
+    use crate::framebuffer::{Color, Framebuffer};
+    use crate::geometry::{Drawable, Rect};
+    use crate::input::Event;
+    use crate::layout::*;
+    use crate::widgets::{Button, ProgressBar, Widget, WidgetList};
+
+    fn bar() -> ProgressBar {
+        ProgressBar::new(BBox::new((0, 0), (0, 0)), 50, 100, Color::Black)
+    }
+
+    fn corners<W: Widget>(widget: &W) -> ((i32, i32), (i32, i32)) {
+        let bbox = widget.bbox();
+        (bbox.start, bbox.end)
+    }
+
+    #[test]
+    fn test_stack() {
+        // Preferred widths don't fit, so the bars are shrunk evenly
+        let row = HStack::new(BBox::new((0, 0), (99, 9)), [bar(), bar()]).spacing(2);
+        assert_eq!(corners(&row.children()[0]), ((0, 0), (48, 9)));
+        assert_eq!(corners(&row.children()[1]), ((51, 0), (99, 9)));
+        assert_eq!(row.min_size(), Size::new(9, 3));
+        assert_eq!(row.preferred_size(), Size::new(131, 8));
+
+        let row = row.align(Alignment::End);
+        assert_eq!(corners(&row.children()[1]), ((51, 1), (99, 9)));
+
+        // Nothing fits, the bars get their minimal heights
+        let column = VStack::new(BBox::new((0, 0), (19, 5)), [bar(), bar()]);
+        assert_eq!(corners(&column.children()[0]), ((0, 0), (19, 3)));
+        assert_eq!(corners(&column.children()[1]), ((0, 4), (19, 7)));
+
+        let column = VStack::new(BBox::new((0, 0), (19, 39)), [bar(), bar()])
+            .justify(Alignment::End)
+            .align(Alignment::Center);
+        assert_eq!(corners(&column.children()[1]), ((0, 31), (19, 39)));
+
+        let mut nested = VStack::new(
+            BBox::new((0, 0), (63, 31)),
+            [
+                HStack::new(BBox::new((0, 0), (0, 0)), [bar(), bar()]),
+                HStack::new(BBox::new((0, 0), (0, 0)), [bar(), bar()]),
+            ],
+        );
+        assert_eq!(
+            corners(&nested.children()[1].children()[1]),
+            ((32, 9), (63, 17))
+        );
+
+        nested.set_bbox(BBox::new((10, 10), (73, 41)));
+        assert_eq!(
+            corners(&nested.children()[1].children()[1]),
+            ((42, 19), (73, 27))
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let button = |label| Button::new(BBox::new((0, 0), (0, 0)), label, Color::Black);
+        let mut grid = Grid::new(
+            BBox::new((0, 0), (63, 31)),
+            [Track::Fixed(12), Track::Fraction(1)],
+            [Track::Fraction(1), Track::Fraction(2)],
+            [button("A"), button("B"), button("C"), button("D")],
+        )
+        .spacing((3, 2));
+
+        assert_eq!(corners(&grid.cells()[0]), ((0, 0), (19, 11)));
+        assert_eq!(corners(&grid.cells()[1]), ((23, 0), (63, 11)));
+        assert_eq!(corners(&grid.cells()[3]), ((23, 14), (63, 31)));
+
+        // The second column has to be twice as wide as the widest button
+        let width = grid.cells()[0].min_size().width;
+        assert_eq!(grid.min_size().width, (width + 1) * 3 + 3 - 1);
+
+        assert!(grid.handle_event(&Event::TouchDown(Point::new(40, 20))));
+        assert!(grid.handle_event(&Event::TouchUp(Point::new(40, 20))));
+        assert!(grid.cells_mut()[3].take_clicked());
+        assert!(!grid.cells_mut()[1].take_clicked());
+        assert!(!grid.handle_event(&Event::TouchDown(Point::new(21, 20))));
+    }
+
+    #[test]
+    fn test_mixed_children() {
+        let mut buffer = [0; 64 * 4];
+        let mut framebuffer = Framebuffer::new(64, 32, &mut buffer).unwrap();
+        let button = Button::new(BBox::new((0, 0), (0, 0)), "OK", Color::Black);
+        let mut row = HStack::new(BBox::new((0, 0), (63, 11)), (button, bar())).spacing(4);
+
+        assert_eq!(corners(&row.children().0), ((0, 0), (17, 11)));
+        assert_eq!(corners(&row.children().1), ((22, 0), (63, 11)));
+        assert!(row.redraw(&mut framebuffer));
+        assert!(!row.needs_redraw());
+
+        row.children_mut().1.set_value(80);
+        assert!(row.redraw(&mut framebuffer));
+        assert!(row.handle_event(&Event::TouchDown(Point::new(2, 2))));
+        assert!(row.children().0.is_pressed());
+
+        let grid = Grid::new(
+            BBox::new((0, 12), (63, 31)),
+            [Track::Fraction(1), Track::Fraction(1)],
+            [Track::Fraction(1)],
+            (bar(), row),
+        );
+        assert_eq!(
+            grid.cells().bbox(grid.index(1, 0)),
+            BBox::new((0, 22), (63, 31))
+        );
+        assert_eq!(corners(&grid.cells().1.children().1).1, (63, 31));
+    }
+
+    #[test]
+    fn test_padding() {
+        let mut buffer = [0; 32 * 2];
+        let mut framebuffer = Framebuffer::new(32, 16, &mut buffer).unwrap();
+        Rect::new_filled(BBox::new((0, 0), (31, 15)), Color::Black).draw(&mut framebuffer);
+
+        let bbox = BBox::new((0, 0), (15, 15));
+        let mut padded = Padding::new(bbox, bar(), (2, 3), Color::Black);
+        assert_eq!(corners(padded.child()), ((2, 3), (13, 12)));
+        assert_eq!(padded.min_size(), Size::new(7, 9));
+
+        let bbox = BBox::new((16, 0), (31, 15));
+        let mut spaced = Margin::new(bbox, bar(), (2, 3));
+        assert_eq!(corners(spaced.child()), ((18, 3), (29, 12)));
+        assert_eq!(spaced.preferred_size(), Size::new(68, 14));
+
+        assert!(padded.redraw(&mut framebuffer));
+        assert!(spaced.redraw(&mut framebuffer));
+        assert!(!padded.redraw(&mut framebuffer));
+
+        // Padding is cleared, the margin is left untouched
+        assert_eq!(framebuffer.get_pixel(0, 0), Color::White);
+        assert_eq!(framebuffer.get_pixel(16, 0), Color::Black);
+        assert_eq!(framebuffer.get_pixel(2, 3), Color::Black);
+        assert_eq!(framebuffer.get_pixel(18, 3), Color::Black);
+    }
+}
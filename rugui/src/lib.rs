@@ -4,7 +4,26 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 pub mod coordinates;
+pub mod double_buffer;
+mod double_buffer_tests;
+pub mod fixed;
+mod fixed_tests;
+pub mod focus;
+mod focus_tests;
 pub mod framebuffer;
+mod framebuffer_tests;
 pub mod geometry;
 mod geometry_tests;
+pub mod input;
+mod input_tests;
+pub mod layout;
+mod layout_tests;
+pub mod strip;
+mod strip_tests;
+pub mod terminal;
+mod terminal_tests;
+pub mod text;
+pub mod transform;
+mod transform_tests;
 pub mod widgets;
+mod widgets_tests;
@@ -0,0 +1,121 @@
+use super::framebuffer::*;
+
+/// Renders a screen in horizontal strips, so only a part of the framebuffer has to be in RAM.
+///
+/// The scene is replayed once per strip. Every pixel outside of the current strip is clipped,
+/// so all drawables work unchanged, and each strip is flushed before the next one is rendered.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::geometry::{Drawable, Rect};
+/// # use rugui::strip::StripRenderer;
+/// // 320x240 screen with only 320x16 pixels buffer
+/// let mut buffer = [0; 640];
+/// let mut renderer = StripRenderer::new(320, 240, &mut buffer).unwrap();
+///
+/// renderer.render(
+///     |canvas| Rect::new(BBox::new((10, 10), (300, 200)), Color::Black).draw(canvas),
+///     |strip| {
+///         // Send `strip.as_bytes()` to the display at row `strip.offset()`
+///         # assert_eq!(strip.as_bytes().len(), 640);
+///     },
+/// );
+/// ```
+pub struct StripRenderer<'a> {
+    width: i32,
+    height: i32,
+    strip_height: i32,
+    buffer: &'a mut [u8],
+}
+
+/// Part of the screen which is currently rendered. Drawing is done in screen coordinates.
+pub struct Strip<'b> {
+    offset: i32,
+    framebuffer: Framebuffer<'b>,
+}
+
+impl<'a> StripRenderer<'a> {
+    /// Strip height is the number of whole 8 pixel pages which fit into `buffer`.
+    /// Returns `None` if the buffer can't hold even a single page.
+    pub fn new(width: i32, height: i32, buffer: &'a mut [u8]) -> Option<Self> {
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        let pages = buffer.len() as i32 / width;
+        if pages == 0 {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            strip_height: (pages * 8).min(height),
+            buffer,
+        })
+    }
+
+    pub fn get_strip_height(&self) -> i32 {
+        self.strip_height
+    }
+
+    /// Call `scene` for every strip from top to bottom and pass the result to `flush`.
+    pub fn render<S, F>(&mut self, mut scene: S, mut flush: F)
+    where
+        S: FnMut(&mut Strip<'_>),
+        F: FnMut(&Strip<'_>),
+    {
+        let mut offset = 0;
+
+        while offset < self.height {
+            let height = self.strip_height.min(self.height - offset);
+            let size = buffer_size(self.width as usize, height as usize);
+            let memory = &mut self.buffer[..size];
+            memory.fill(0);
+
+            // Buffer size is checked above
+            let framebuffer = Framebuffer::new(self.width, height, memory).unwrap();
+            let mut strip = Strip {
+                offset,
+                framebuffer,
+            };
+
+            scene(&mut strip);
+            flush(&strip);
+
+            offset += height;
+        }
+    }
+}
+
+impl<'b> Strip<'b> {
+    /// Screen row of the first line of the strip.
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    pub fn get_height(&self) -> i32 {
+        self.framebuffer.get_height()
+    }
+
+    pub fn get_width(&self) -> i32 {
+        self.framebuffer.get_width()
+    }
+
+    /// Pixel in screen coordinates. Returns `Color::White` outside of the strip.
+    pub fn get_pixel(&self, x: i32, y: i32) -> Color {
+        self.framebuffer.get_pixel(x, y - self.offset)
+    }
+
+    /// Raw strip memory in the display format.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.framebuffer.as_bytes()
+    }
+}
+
+impl<'b> PixelDraw for Strip<'b> {
+    fn draw_pixel(&mut self, x: i32, y: i32, color: &Color) -> bool {
+        self.framebuffer.draw_pixel(x, y - self.offset, color)
+    }
+}
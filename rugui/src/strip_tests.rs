@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::bounding_box::BBox;
+    use crate::framebuffer::{Color, Framebuffer, PixelDraw};
+    use crate::geometry::{Circle, Drawable, Line, Rect};
+    use crate::strip::StripRenderer;
+
+    fn scene<C: PixelDraw>(canvas: &mut C) {
+        Rect::new(BBox::new((1, 1), (30, 20)), Color::Black).draw(canvas);
+        Circle::new((16, 12), 7, Color::Black).draw(canvas);
+        Line::new(BBox::new((0, 0), (31, 23)), Color::Black).draw(canvas);
+    }
+
+    #[test]
+    fn test_strips_match_full_frame() {
+        let mut full = [0; 32 * 3];
+        let mut framebuffer = Framebuffer::new(32, 23, &mut full).unwrap();
+        scene(&mut framebuffer);
+
+        let mut buffer = [0; 32];
+        let mut renderer = StripRenderer::new(32, 23, &mut buffer).unwrap();
+        assert_eq!(renderer.get_strip_height(), 8);
+
+        let mut strips = 0;
+        renderer.render(
+            |canvas| scene(canvas),
+            |strip| {
+                let offset = strip.offset() as usize;
+                assert_eq!(strip.as_bytes(), &full[offset * 4..offset * 4 + 32]);
+                strips += 1;
+            },
+        );
+        assert_eq!(strips, 3);
+    }
+
+    #[test]
+    fn test_buffer_too_small() {
+        let mut buffer = [0; 31];
+        assert!(StripRenderer::new(32, 16, &mut buffer).is_none());
+    }
+}
@@ -0,0 +1,151 @@
+use super::framebuffer::*;
+use core::fmt;
+
+/// Set of characters used to represent pixels in a terminal.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Glyphs {
+    /// Each character covers 1 x 2 pixels using `▀`, `▄` and `█`.
+    HalfBlock,
+    /// Each character covers 2 x 4 pixels using Unicode braille patterns.
+    Braille,
+}
+
+/// Renders a framebuffer as text, so it can be printed over SSH or compared in tests.
+///
+/// ```
+/// # use rugui::framebuffer::Framebuffer;
+/// # use rugui::terminal::TerminalRenderer;
+/// let mut buffer = [0; 16];
+/// let framebuffer = Framebuffer::new(8, 16, &mut buffer).unwrap();
+/// let text = TerminalRenderer::new(&framebuffer).to_string();
+/// assert_eq!(text.lines().count(), 8);
+/// ```
+pub struct TerminalRenderer<'f, 'a> {
+    framebuffer: &'f Framebuffer<'a>,
+    glyphs: Glyphs,
+    ansi: bool,
+}
+
+impl<'f, 'a> TerminalRenderer<'f, 'a> {
+    pub fn new(framebuffer: &'f Framebuffer<'a>) -> Self {
+        Self {
+            framebuffer,
+            glyphs: Glyphs::HalfBlock,
+            ansi: false,
+        }
+    }
+
+    pub fn glyphs(mut self, glyphs: Glyphs) -> Self {
+        self.glyphs = glyphs;
+
+        self
+    }
+
+    /// Emit ANSI color escape codes. In half-block mode every character becomes `▀`
+    /// with the top pixel as a foreground and the bottom pixel as a background color.
+    pub fn ansi(mut self, ansi: bool) -> Self {
+        self.ansi = ansi;
+
+        self
+    }
+
+    /// Pixels outside of the framebuffer are treated as `Color::White`.
+    fn pixel(&self, x: i32, y: i32) -> Color {
+        if x >= self.framebuffer.get_width() || y >= self.framebuffer.get_height() {
+            return Color::White;
+        }
+
+        self.framebuffer.get_pixel(x, y)
+    }
+
+    fn half_block_line(&self, f: &mut fmt::Formatter<'_>, y: i32) -> fmt::Result {
+        let mut current = None;
+
+        for x in 0..self.framebuffer.get_width() {
+            let top = self.pixel(x, y);
+            let bottom = self.pixel(x, y + 1);
+
+            if self.ansi {
+                if current != Some((top, bottom)) {
+                    write!(f, "\x1b[{};{}m", fg_code(top), fg_code(bottom) + 10)?;
+                    current = Some((top, bottom));
+                }
+                f.write_str("▀")?;
+                continue;
+            }
+
+            let glyph = match (top, bottom) {
+                (Color::Black, Color::Black) => "█",
+                (Color::Black, Color::White) => "▀",
+                (Color::White, Color::Black) => "▄",
+                (Color::White, Color::White) => " ",
+            };
+            f.write_str(glyph)?;
+        }
+
+        Ok(())
+    }
+
+    fn braille_line(&self, f: &mut fmt::Formatter<'_>, y: i32) -> fmt::Result {
+        // Bit offsets of braille dots, indexed by [dx][dy]
+        const DOTS: [[u32; 4]; 2] = [[0, 1, 2, 6], [3, 4, 5, 7]];
+
+        if self.ansi {
+            write!(
+                f,
+                "\x1b[{};{}m",
+                fg_code(Color::Black),
+                fg_code(Color::White) + 10
+            )?;
+        }
+
+        for x in (0..self.framebuffer.get_width()).step_by(2) {
+            let mut pattern = 0;
+            for (dx, column) in DOTS.iter().enumerate() {
+                for (dy, bit) in column.iter().enumerate() {
+                    if self.pixel(x + dx as i32, y + dy as i32) == Color::Black {
+                        pattern |= 1 << bit;
+                    }
+                }
+            }
+
+            // All values in 0x2800..=0x28FF are valid braille characters
+            let glyph = char::from_u32(0x2800 + pattern).unwrap_or(' ');
+            write!(f, "{}", glyph)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'f, 'a> fmt::Display for TerminalRenderer<'f, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows_per_line = match self.glyphs {
+            Glyphs::HalfBlock => 2,
+            Glyphs::Braille => 4,
+        };
+
+        for y in (0..self.framebuffer.get_height()).step_by(rows_per_line) {
+            match self.glyphs {
+                Glyphs::HalfBlock => self.half_block_line(f, y)?,
+                Glyphs::Braille => self.braille_line(f, y)?,
+            }
+
+            if self.ansi {
+                f.write_str("\x1b[0m")?;
+            }
+            f.write_str("\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// ANSI foreground color code. Add 10 to get a background color.
+/// `Color::Black` is a lit pixel, so it's rendered bright, just like in the emulator.
+fn fg_code(color: Color) -> u8 {
+    match color {
+        Color::Black => 97,
+        Color::White => 30,
+    }
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::string::ToString;
+
+    use crate::framebuffer::{Color, Framebuffer, PixelDraw};
+    use crate::terminal::{Glyphs, TerminalRenderer};
+
+    #[test]
+    fn test_half_block() {
+        let mut buffer = [0; 4];
+        let mut framebuffer = Framebuffer::new(4, 8, &mut buffer).unwrap();
+        framebuffer.draw_pixel(0, 0, &Color::Black);
+        framebuffer.draw_pixel(1, 1, &Color::Black);
+        framebuffer.draw_pixel(2, 0, &Color::Black);
+        framebuffer.draw_pixel(2, 1, &Color::Black);
+        framebuffer.draw_pixel(3, 7, &Color::Black);
+
+        let text = TerminalRenderer::new(&framebuffer).to_string();
+        assert_eq!(text, "▀▄█ \n    \n    \n   ▄\n");
+    }
+
+    #[test]
+    fn test_braille() {
+        let mut buffer = [0; 4];
+        let mut framebuffer = Framebuffer::new(4, 8, &mut buffer).unwrap();
+        framebuffer.draw_pixel(0, 0, &Color::Black);
+        framebuffer.draw_pixel(1, 3, &Color::Black);
+        framebuffer.draw_pixel(3, 7, &Color::Black);
+
+        let text = TerminalRenderer::new(&framebuffer)
+            .glyphs(Glyphs::Braille)
+            .to_string();
+        assert_eq!(text, "⢁⠀\n⠀⢀\n");
+    }
+
+    #[test]
+    fn test_ansi() {
+        let mut buffer = [0; 2];
+        let mut framebuffer = Framebuffer::new(2, 2, &mut buffer).unwrap();
+        framebuffer.draw_pixel(0, 0, &Color::Black);
+
+        let text = TerminalRenderer::new(&framebuffer).ansi(true).to_string();
+        assert_eq!(text, "\x1b[97;40m▀\x1b[30;40m▀\x1b[0m\n");
+    }
+}
@@ -0,0 +1,354 @@
+use super::coordinates::bounding_box::*;
+use super::coordinates::cvec::{Point, Vec2};
+use super::framebuffer::*;
+use super::geometry::Drawable;
+use super::transform::Transformable;
+
+/// Monospaced bitmap font. Every glyph is stored column by column, least significant bit on top.
+pub struct Font {
+    /// Glyph width without spacing
+    pub width: u32,
+    /// Glyph height, up to 8 pixels
+    pub height: u32,
+    /// Space between glyphs
+    pub spacing: u32,
+    /// First encoded character
+    pub first: char,
+    pub glyphs: &'static [u8],
+}
+
+/// Classic 5x7 font, covering printable ASCII characters.
+pub const FONT_5X7: Font = Font {
+    width: 5,
+    height: 7,
+    spacing: 1,
+    first: ' ',
+    glyphs: &GLYPHS_5X7,
+};
+
+impl Font {
+    /// Columns of the glyph. Unknown characters are rendered as `?`.
+    pub fn glyph(&self, c: char) -> &[u8] {
+        let count = self.glyphs.len() / self.width as usize;
+        let index = (c as u32).wrapping_sub(self.first as u32) as usize;
+        let index = if index < count {
+            index
+        } else {
+            ('?' as u32 - self.first as u32) as usize
+        };
+
+        let start = index * self.width as usize;
+        &self.glyphs[start..start + self.width as usize]
+    }
+
+    /// Horizontal distance between two glyphs.
+    pub fn advance(&self) -> u32 {
+        self.width + self.spacing
+    }
+
+    /// Size of a single line of text in pixels, without the trailing spacing.
+    pub fn text_size(&self, text: &str) -> Vec2 {
+        let count = text.chars().count() as u32;
+        let width = (count * self.advance()).saturating_sub(self.spacing);
+
+        (width as i32, self.height as i32)
+    }
+
+    /// Split the text into lines no wider than `width` pixels.
+    ///
+    /// Lines are broken at spaces and at `\n`, words longer than a line are broken anywhere.
+    /// Spaces at a break are dropped.
+    ///
+    /// ```
+    /// # use rugui::text::FONT_5X7;
+    /// let mut lines = FONT_5X7.wrap("Save changes before exit?", 72);
+    /// assert_eq!(lines.next(), Some("Save changes"));
+    /// assert_eq!(lines.next(), Some("before exit?"));
+    /// assert_eq!(lines.next(), None);
+    ///
+    /// let lines = FONT_5X7.wrap("ab  cd   \nef", 12);
+    /// assert!(lines.eq(["ab", "cd", "ef"]));
+    /// ```
+    pub fn wrap<'a>(&self, text: &'a str, width: i32) -> Wrap<'a> {
+        let chars = (width + self.spacing as i32) / self.advance() as i32;
+
+        Wrap {
+            text,
+            chars: chars.max(1) as usize,
+        }
+    }
+}
+
+/// Lines of a wrapped text, see `Font::wrap`.
+pub struct Wrap<'a> {
+    text: &'a str,
+    /// Maximal number of characters in a line
+    chars: usize,
+}
+
+impl<'a> Iterator for Wrap<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.text.is_empty() {
+            return None;
+        }
+
+        let mut space = None;
+        let mut end = self.text.len();
+        for (count, (index, c)) in self.text.char_indices().enumerate() {
+            if c == '\n' {
+                end = index;
+                space = Some(index);
+                break;
+            }
+            if count == self.chars {
+                end = index;
+                break;
+            }
+            if c == ' ' {
+                space = Some(index);
+            }
+        }
+
+        // The whole rest fits, or the line ends with a line break
+        let text = self.text;
+        if end == text.len() || space == Some(end) {
+            self.text = text.get(end + 1..).unwrap_or("");
+            return Some(&text[..end]);
+        }
+
+        // A space right after the last character breaks the line too
+        let space = if text[end..].starts_with(' ') {
+            Some(end)
+        } else {
+            space
+        };
+
+        match space {
+            Some(space) => {
+                // The line is broken already, spaces and a line break right after it are dropped
+                let rest = text[space + 1..].trim_start_matches(' ');
+                self.text = rest.strip_prefix('\n').unwrap_or(rest);
+                Some(&text[..space])
+            }
+            None => {
+                self.text = &text[end..];
+                Some(&text[..end])
+            }
+        }
+    }
+}
+
+/// A single line of text. `position` is the top left corner of the first glyph.
+///
+/// ```
+/// # use rugui::coordinates::cvec::Point;
+/// # use rugui::framebuffer::{Color, Framebuffer};
+/// # use rugui::geometry::Drawable;
+/// # use rugui::text::Text;
+/// let mut buffer = [0; 16];
+/// let mut framebuffer = Framebuffer::new(16, 8, &mut buffer).unwrap();
+/// Text::new(Point::new(2, 0), "I", Color::Black).draw(&mut framebuffer);
+///
+/// assert_eq!(framebuffer.get_pixel(4, 0), Color::Black);
+/// ```
+pub struct Text<'a> {
+    position: Vec2,
+    text: &'a str,
+    font: &'a Font,
+    color: Color,
+}
+
+impl<'a> Text<'a> {
+    pub fn new(position: impl Into<Point>, text: &'a str, color: Color) -> Self {
+        Self {
+            position: position.into().into(),
+            text,
+            font: &FONT_5X7,
+            color,
+        }
+    }
+
+    pub fn font(mut self, font: &'a Font) -> Self {
+        self.font = font;
+
+        self
+    }
+
+    /// Place the text within a bounding box.
+    pub fn align(mut self, bbox: BBox, horizontal: Alignment, vertical: Alignment) -> Self {
+        let (width, height) = self.size();
+        let x = align(bbox.start.0, bbox.end.0, width, horizontal);
+        let y = align(bbox.start.1, bbox.end.1, height, vertical);
+        self.position = (x, y);
+
+        self
+    }
+
+    pub fn size(&self) -> Vec2 {
+        self.font.text_size(self.text)
+    }
+}
+
+/// Start coordinate of an object with the `length` within `start..=end`.
+fn align(start: i32, end: i32, length: i32, alignment: Alignment) -> i32 {
+    let free = end - start + 1 - length;
+
+    match alignment {
+        Alignment::Start => start,
+        Alignment::Center => start + free / 2,
+        Alignment::End => start + free,
+    }
+}
+
+/// Text is resampled like a bitmap.
+impl<'a> Transformable for Text<'a> {}
+
+impl<'a> Drawable for Text<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let (mut x, y) = self.position;
+
+        for c in self.text.chars() {
+            for column in self.font.glyph(c) {
+                for dy in 0..self.font.height {
+                    if column & (1 << dy) != 0 {
+                        canvas.draw_pixel(x, y + dy as i32, &self.color);
+                    }
+                }
+                x += 1;
+            }
+
+            x += self.font.spacing as i32;
+        }
+    }
+}
+
+/// Length of the longest `i32`, with the sign.
+pub(crate) const MAX_NUMBER: usize = 11;
+
+/// Format a number into the buffer without an allocation.
+pub(crate) fn format_number(value: i32, buffer: &mut [u8; MAX_NUMBER]) -> &str {
+    let mut digits = [0; MAX_NUMBER];
+    let mut count = 0;
+    let mut rest = value.unsigned_abs();
+    loop {
+        digits[count] = b'0' + (rest % 10) as u8;
+        count += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+
+    let mut len = 0;
+    if value < 0 {
+        buffer[0] = b'-';
+        len = 1;
+    }
+    for &digit in digits[..count].iter().rev() {
+        buffer[len] = digit;
+        len += 1;
+    }
+
+    core::str::from_utf8(&buffer[..len]).unwrap_or("")
+}
+
+#[rustfmt::skip]
+static GLYPHS_5X7: [u8; 475] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+    0x00, 0x00, 0x5F, 0x00, 0x00, // !
+    0x00, 0x07, 0x00, 0x07, 0x00, // "
+    0x14, 0x7F, 0x14, 0x7F, 0x14, // #
+    0x24, 0x2A, 0x7F, 0x2A, 0x12, // $
+    0x23, 0x13, 0x08, 0x64, 0x62, // %
+    0x36, 0x49, 0x55, 0x22, 0x50, // &
+    0x00, 0x05, 0x03, 0x00, 0x00, // '
+    0x00, 0x1C, 0x22, 0x41, 0x00, // (
+    0x00, 0x41, 0x22, 0x1C, 0x00, // )
+    0x08, 0x2A, 0x1C, 0x2A, 0x08, // *
+    0x08, 0x08, 0x3E, 0x08, 0x08, // +
+    0x00, 0x50, 0x30, 0x00, 0x00, // ,
+    0x08, 0x08, 0x08, 0x08, 0x08, // -
+    0x00, 0x60, 0x60, 0x00, 0x00, // .
+    0x20, 0x10, 0x08, 0x04, 0x02, // /
+    0x3E, 0x51, 0x49, 0x45, 0x3E, // 0
+    0x00, 0x42, 0x7F, 0x40, 0x00, // 1
+    0x42, 0x61, 0x51, 0x49, 0x46, // 2
+    0x21, 0x41, 0x45, 0x4B, 0x31, // 3
+    0x18, 0x14, 0x12, 0x7F, 0x10, // 4
+    0x27, 0x45, 0x45, 0x45, 0x39, // 5
+    0x3C, 0x4A, 0x49, 0x49, 0x30, // 6
+    0x01, 0x71, 0x09, 0x05, 0x03, // 7
+    0x36, 0x49, 0x49, 0x49, 0x36, // 8
+    0x06, 0x49, 0x49, 0x29, 0x1E, // 9
+    0x00, 0x36, 0x36, 0x00, 0x00, // :
+    0x00, 0x56, 0x36, 0x00, 0x00, // ;
+    0x08, 0x14, 0x22, 0x41, 0x00, // <
+    0x14, 0x14, 0x14, 0x14, 0x14, // =
+    0x00, 0x41, 0x22, 0x14, 0x08, // >
+    0x02, 0x01, 0x51, 0x09, 0x06, // ?
+    0x32, 0x49, 0x79, 0x41, 0x3E, // @
+    0x7E, 0x11, 0x11, 0x11, 0x7E, // A
+    0x7F, 0x49, 0x49, 0x49, 0x36, // B
+    0x3E, 0x41, 0x41, 0x41, 0x22, // C
+    0x7F, 0x41, 0x41, 0x22, 0x1C, // D
+    0x7F, 0x49, 0x49, 0x49, 0x41, // E
+    0x7F, 0x09, 0x09, 0x09, 0x01, // F
+    0x3E, 0x41, 0x49, 0x49, 0x7A, // G
+    0x7F, 0x08, 0x08, 0x08, 0x7F, // H
+    0x00, 0x41, 0x7F, 0x41, 0x00, // I
+    0x20, 0x40, 0x41, 0x3F, 0x01, // J
+    0x7F, 0x08, 0x14, 0x22, 0x41, // K
+    0x7F, 0x40, 0x40, 0x40, 0x40, // L
+    0x7F, 0x02, 0x0C, 0x02, 0x7F, // M
+    0x7F, 0x04, 0x08, 0x10, 0x7F, // N
+    0x3E, 0x41, 0x41, 0x41, 0x3E, // O
+    0x7F, 0x09, 0x09, 0x09, 0x06, // P
+    0x3E, 0x41, 0x51, 0x21, 0x5E, // Q
+    0x7F, 0x09, 0x19, 0x29, 0x46, // R
+    0x46, 0x49, 0x49, 0x49, 0x31, // S
+    0x01, 0x01, 0x7F, 0x01, 0x01, // T
+    0x3F, 0x40, 0x40, 0x40, 0x3F, // U
+    0x1F, 0x20, 0x40, 0x20, 0x1F, // V
+    0x3F, 0x40, 0x38, 0x40, 0x3F, // W
+    0x63, 0x14, 0x08, 0x14, 0x63, // X
+    0x07, 0x08, 0x70, 0x08, 0x07, // Y
+    0x61, 0x51, 0x49, 0x45, 0x43, // Z
+    0x00, 0x7F, 0x41, 0x41, 0x00, // [
+    0x02, 0x04, 0x08, 0x10, 0x20, // \
+    0x00, 0x41, 0x41, 0x7F, 0x00, // ]
+    0x04, 0x02, 0x01, 0x02, 0x04, // ^
+    0x40, 0x40, 0x40, 0x40, 0x40, // _
+    0x00, 0x01, 0x02, 0x04, 0x00, // `
+    0x20, 0x54, 0x54, 0x54, 0x78, // a
+    0x7F, 0x48, 0x44, 0x44, 0x38, // b
+    0x38, 0x44, 0x44, 0x44, 0x20, // c
+    0x38, 0x44, 0x44, 0x48, 0x7F, // d
+    0x38, 0x54, 0x54, 0x54, 0x18, // e
+    0x08, 0x7E, 0x09, 0x01, 0x02, // f
+    0x0C, 0x52, 0x52, 0x52, 0x3E, // g
+    0x7F, 0x08, 0x04, 0x04, 0x78, // h
+    0x00, 0x44, 0x7D, 0x40, 0x00, // i
+    0x20, 0x40, 0x44, 0x3D, 0x00, // j
+    0x7F, 0x10, 0x28, 0x44, 0x00, // k
+    0x00, 0x41, 0x7F, 0x40, 0x00, // l
+    0x7C, 0x04, 0x18, 0x04, 0x78, // m
+    0x7C, 0x08, 0x04, 0x04, 0x78, // n
+    0x38, 0x44, 0x44, 0x44, 0x38, // o
+    0x7C, 0x14, 0x14, 0x14, 0x08, // p
+    0x08, 0x14, 0x14, 0x18, 0x7C, // q
+    0x7C, 0x08, 0x04, 0x04, 0x08, // r
+    0x48, 0x54, 0x54, 0x54, 0x20, // s
+    0x04, 0x3F, 0x44, 0x40, 0x20, // t
+    0x3C, 0x40, 0x40, 0x20, 0x7C, // u
+    0x1C, 0x20, 0x40, 0x20, 0x1C, // v
+    0x3C, 0x40, 0x30, 0x40, 0x3C, // w
+    0x44, 0x28, 0x10, 0x28, 0x44, // x
+    0x0C, 0x50, 0x50, 0x50, 0x3C, // y
+    0x44, 0x64, 0x54, 0x4C, 0x44, // z
+    0x00, 0x08, 0x36, 0x41, 0x00, // {
+    0x00, 0x00, 0x7F, 0x00, 0x00, // |
+    0x00, 0x41, 0x36, 0x08, 0x00, // }
+    0x08, 0x04, 0x08, 0x10, 0x08, // ~
+];
@@ -0,0 +1,360 @@
+//! Affine transforms of shapes: translation, scaling, rotation and flips.
+//!
+//! Pixel `(x, y)` covers the square from `(x, y)` to `(x + 1, y + 1)`, so flipping
+//! or scaling a shape keeps its pixels aligned. Vector shapes are transformed by their
+//! points, anything else is resampled pixel by pixel. Both cover the same pixels, so
+//! an icon made of a bitmap and a frame scales as one piece.
+
+use crate::coordinates::cvec::Vec2;
+use crate::fixed::{cos, sin, Fixed};
+use crate::framebuffer::*;
+use crate::geometry::Drawable;
+
+/// Mapping of coordinates which keeps straight lines straight.
+///
+/// Operations are applied in the order of the calls, around the origin.
+///
+/// ```
+/// # use rugui::transform::Transform;
+/// let transform = Transform::new().scale(2, 2).translate(10, 0);
+/// assert_eq!(transform.apply((0, 0)), (10, 0));
+/// assert_eq!(transform.apply((3, 1)), (16, 2));
+///
+/// // Quarter turn counterclockwise on the screen around the pixel (4, 4)
+/// let transform = Transform::new().rotate_around((4, 4), 90);
+/// assert_eq!(transform.apply((8, 4)), (4, 0));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct Transform {
+    // x' = xx * x + xy * y + tx, y' = yx * x + yy * y + ty
+    pub(crate) xx: Fixed,
+    pub(crate) xy: Fixed,
+    pub(crate) yx: Fixed,
+    pub(crate) yy: Fixed,
+    pub(crate) tx: Fixed,
+    pub(crate) ty: Fixed,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transform {
+    /// Transform which keeps everything in place.
+    pub const fn new() -> Self {
+        Self {
+            xx: Fixed::ONE,
+            xy: Fixed::ZERO,
+            yx: Fixed::ZERO,
+            yy: Fixed::ONE,
+            tx: Fixed::ZERO,
+            ty: Fixed::ZERO,
+        }
+    }
+
+    /// Apply `next` after this transform.
+    pub fn then(self, next: Self) -> Self {
+        Self {
+            xx: next.xx * self.xx + next.xy * self.yx,
+            xy: next.xx * self.xy + next.xy * self.yy,
+            yx: next.yx * self.xx + next.yy * self.yx,
+            yy: next.yx * self.xy + next.yy * self.yy,
+            tx: next.xx * self.tx + next.xy * self.ty + next.tx,
+            ty: next.yx * self.tx + next.yy * self.ty + next.ty,
+        }
+    }
+
+    /// Move by the distance, which may be a fraction of a pixel.
+    pub fn translate(self, dx: impl Into<Fixed>, dy: impl Into<Fixed>) -> Self {
+        let (tx, ty) = (dx.into(), dy.into());
+
+        self.then(Self {
+            tx,
+            ty,
+            ..Self::new()
+        })
+    }
+
+    /// Stretch away from the origin. Negative factors flip.
+    pub fn scale(self, sx: impl Into<Fixed>, sy: impl Into<Fixed>) -> Self {
+        let (xx, yy) = (sx.into(), sy.into());
+
+        self.then(Self {
+            xx,
+            yy,
+            ..Self::new()
+        })
+    }
+
+    /// Stretch away from the top left corner of the `origin` pixel, e.g. the position
+    /// of a bitmap, which then grows to the right and down.
+    pub fn scale_around(self, origin: Vec2, sx: impl Into<Fixed>, sy: impl Into<Fixed>) -> Self {
+        self.translate(-origin.0, -origin.1)
+            .scale(sx, sy)
+            .translate(origin.0, origin.1)
+    }
+
+    /// Turn counterclockwise on the screen around the origin. The screen Y axis points
+    /// down, like with `fixed::polar`.
+    pub fn rotate(self, degrees: impl Into<Fixed>) -> Self {
+        let degrees = degrees.into();
+        let (sin, cos) = (sin(degrees), cos(degrees));
+
+        self.then(Self {
+            xx: cos,
+            xy: sin,
+            yx: -sin,
+            yy: cos,
+            ..Self::new()
+        })
+    }
+
+    /// Turn counterclockwise on the screen around the middle of the `center` pixel.
+    pub fn rotate_around(self, center: Vec2, degrees: impl Into<Fixed>) -> Self {
+        let x = Fixed::from_int(center.0) + Fixed::HALF;
+        let y = Fixed::from_int(center.1) + Fixed::HALF;
+
+        self.translate(-x, -y).rotate(degrees).translate(x, y)
+    }
+
+    /// Mirror left to right around the origin.
+    pub fn flip_horizontal(self) -> Self {
+        self.scale(-1, 1)
+    }
+
+    /// Mirror top to bottom around the origin.
+    pub fn flip_vertical(self) -> Self {
+        self.scale(1, -1)
+    }
+
+    /// Transform which undoes this one, `None` if it squashes everything into a line.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.xx * self.yy - self.xy * self.yx;
+        if det == Fixed::ZERO {
+            return None;
+        }
+
+        let (xx, xy) = (self.yy / det, -self.xy / det);
+        let (yx, yy) = (-self.yx / det, self.xx / det);
+
+        Some(Self {
+            xx,
+            xy,
+            yx,
+            yy,
+            tx: -(xx * self.tx + xy * self.ty),
+            ty: -(yx * self.tx + yy * self.ty),
+        })
+    }
+
+    /// Map a point with fractional coordinates.
+    pub fn map(&self, (x, y): (Fixed, Fixed)) -> (Fixed, Fixed) {
+        (
+            self.xx * x + self.xy * y + self.tx,
+            self.yx * x + self.yy * y + self.ty,
+        )
+    }
+
+    /// Map a pixel by its middle to the nearest pixel. When the middle lands on a
+    /// corner, e.g. when scaling by two, the top left pixel is taken.
+    pub fn apply(&self, (x, y): Vec2) -> Vec2 {
+        let half = Fixed::HALF;
+        let (x, y) = self.map((Fixed::from_int(x) + half, Fixed::from_int(y) + half));
+
+        ((x - Fixed::ONE).ceil(), (y - Fixed::ONE).ceil())
+    }
+
+    /// Smallest and biggest coordinates of the square of a pixel, mapped.
+    fn map_square(&self, (x, y): Vec2) -> ((Fixed, Fixed), (Fixed, Fixed)) {
+        let (left, top) = (Fixed::from_int(x), Fixed::from_int(y));
+        let (right, bottom) = (left + Fixed::ONE, top + Fixed::ONE);
+        let corners = [(left, top), (right, top), (left, bottom), (right, bottom)]
+            .map(|corner| self.map(corner));
+        let xs = corners.map(|corner| corner.0);
+        let ys = corners.map(|corner| corner.1);
+        let (min_x, max_x) = (xs.iter().min().unwrap(), xs.iter().max().unwrap());
+        let (min_y, max_y) = (ys.iter().min().unwrap(), ys.iter().max().unwrap());
+
+        ((*min_x, *min_y), (*max_x, *max_y))
+    }
+
+    /// Middle of the pixels from `start` to `end`, mapped.
+    pub(crate) fn map_middle(&self, start: Vec2, end: Vec2) -> (Fixed, Fixed) {
+        let middle = |a: i32, b: i32| Fixed::from_int(a + b + 1) / 2;
+
+        self.map((middle(start.0, end.0), middle(start.1, end.1)))
+    }
+
+    /// Map a corner pixel of a shape to the pixel of its square which is the farthest
+    /// from the mapped `middle` of the shape. Corners mapped this way span the same pixels
+    /// as the resampled shape, e.g. scaling by two turns 4 pixels into 8.
+    pub(crate) fn apply_corner(&self, pixel: Vec2, middle: (Fixed, Fixed)) -> Vec2 {
+        let (min, max) = self.map_square(pixel);
+        let nearest = self.apply(pixel);
+        let pick = |min: Fixed, max: Fixed, middle: Fixed, nearest: i32| {
+            // Pixels with their middles in the square
+            let (first, last) = ((min - Fixed::HALF).ceil(), (max - Fixed::HALF).ceil() - 1);
+            if first > last {
+                nearest
+            } else if min + max > middle * 2 {
+                last
+            } else {
+                first
+            }
+        };
+
+        (
+            pick(min.0, max.0, middle.0, nearest.0),
+            pick(min.1, max.1, middle.1, nearest.1),
+        )
+    }
+}
+
+/// Shape which can be drawn with a transform applied.
+///
+/// Vector shapes transform their points, so they stay exact. The default resamples
+/// whatever the shape draws, which suits bitmaps and text.
+pub trait Transformable: Drawable {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        self.draw(&mut Resampled::new(canvas, *transform));
+    }
+}
+
+impl<T: Transformable> Transformable for [T] {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        for item in self {
+            item.draw_transformed(transform, canvas);
+        }
+    }
+}
+
+impl<T: Transformable, const N: usize> Transformable for [T; N] {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        self[..].draw_transformed(transform, canvas);
+    }
+}
+
+/// Transform a group of different shapes together.
+macro_rules! impl_transformable_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Transformable),+> Transformable for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+                let ($($name,)+) = self;
+                $($name.draw_transformed(transform, canvas);)+
+            }
+        }
+    };
+}
+
+impl_transformable_tuple!(A, B);
+impl_transformable_tuple!(A, B, D);
+impl_transformable_tuple!(A, B, D, E);
+impl_transformable_tuple!(A, B, D, E, F);
+impl_transformable_tuple!(A, B, D, E, F, G);
+
+/// Shape or group of shapes drawn with a transform, e.g. an icon reused at another size
+/// or a rotating indicator.
+///
+/// ```
+/// # use rugui::framebuffer::{Color, Framebuffer};
+/// # use rugui::geometry::{Bitmap, Drawable};
+/// # use rugui::transform::{Transform, Transformed};
+/// # let mut buffer = [0; 64 * 8];
+/// # let mut framebuffer = Framebuffer::new(64, 64, &mut buffer).unwrap();
+/// let dot = Bitmap::new(1, 1, &[0x80], Color::Black).at((4, 4));
+///
+/// // Each pixel of the bitmap becomes a 3x3 block
+/// Transformed::new(dot, Transform::new().scale_around((4, 4), 3, 3)).draw(&mut framebuffer);
+/// assert_eq!(framebuffer.get_pixel(6, 6), Color::Black);
+/// assert_eq!(framebuffer.get_pixel(7, 6), Color::White);
+/// ```
+pub struct Transformed<T: Transformable> {
+    shape: T,
+    transform: Transform,
+}
+
+impl<T: Transformable> Transformed<T> {
+    pub fn new(shape: T, transform: Transform) -> Self {
+        Self { shape, transform }
+    }
+
+    pub fn shape(&self) -> &T {
+        &self.shape
+    }
+
+    pub fn shape_mut(&mut self) -> &mut T {
+        &mut self.shape
+    }
+
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+}
+
+impl<T: Transformable> Drawable for Transformed<T> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        self.shape.draw_transformed(&self.transform, canvas);
+    }
+}
+
+impl<T: Transformable> Transformable for Transformed<T> {
+    fn draw_transformed<C: PixelDraw>(&self, transform: &Transform, canvas: &mut C) {
+        let transform = self.transform.then(*transform);
+        self.shape.draw_transformed(&transform, canvas);
+    }
+}
+
+/// Canvas adaptor which transforms everything drawn through it.
+///
+/// Each target pixel takes the source pixel its middle maps back into, i.e. the image is
+/// resampled by the nearest neighbour, so scaled and rotated shapes have no gaps.
+pub struct Resampled<'c, C: PixelDraw> {
+    canvas: &'c mut C,
+    transform: Transform,
+    inverse: Option<Transform>,
+}
+
+impl<'c, C: PixelDraw> Resampled<'c, C> {
+    pub fn new(canvas: &'c mut C, transform: Transform) -> Self {
+        Self {
+            canvas,
+            transform,
+            inverse: transform.inverse(),
+        }
+    }
+}
+
+impl<'c, C: PixelDraw> PixelDraw for Resampled<'c, C> {
+    fn draw_pixel(&mut self, x: i32, y: i32, color: &Color) -> bool {
+        let inverse = match self.inverse {
+            Some(inverse) => inverse,
+            None => return false,
+        };
+
+        // Area covered by the transformed square of the pixel
+        let ((min_x, min_y), (max_x, max_y)) = self.transform.map_square((x, y));
+
+        let mut drawn = false;
+        for ty in (min_y - Fixed::HALF).ceil()..=(max_y - Fixed::HALF).floor() {
+            for tx in (min_x - Fixed::HALF).ceil()..=(max_x - Fixed::HALF).floor() {
+                let middle = (
+                    Fixed::from_int(tx) + Fixed::HALF,
+                    Fixed::from_int(ty) + Fixed::HALF,
+                );
+                let (sx, sy) = inverse.map(middle);
+                if sx.floor() == x && sy.floor() == y {
+                    drawn |= self.canvas.draw_pixel(tx, ty, color);
+                }
+            }
+        }
+
+        drawn
+    }
+}
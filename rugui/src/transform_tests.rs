@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::bounding_box::BBox;
+    use crate::fixed::Fixed;
+    use crate::framebuffer::{Color, Framebuffer};
+    use crate::geometry::{Bitmap, Circle, Drawable, Line, Polygon, Rect};
+    use crate::transform::*;
+
+    /// Compare every pixel of two frames of 32x32 pixels.
+    fn same_frames(a: &Framebuffer<'_>, b: &Framebuffer<'_>) -> bool {
+        (0..32).all(|y| (0..32).all(|x| a.get_pixel(x, y) == b.get_pixel(x, y)))
+    }
+
+    #[test]
+    fn test_transform() {
+        let flip = Transform::new().flip_horizontal().translate(10, 0);
+        assert_eq!(flip.apply((0, 3)), (9, 3));
+        assert_eq!(flip.apply((9, 3)), (0, 3));
+
+        let turn = Transform::new().rotate(90);
+        assert_eq!(turn.apply((3, 0)), (0, -4));
+        assert_eq!(Transform::new().rotate(180).apply((3, 1)), (-4, -2));
+
+        let half = Transform::new().scale(Fixed::HALF, Fixed::HALF);
+        assert_eq!(half.apply((7, 6)), (3, 3));
+
+        let transform = Transform::new()
+            .rotate_around((5, 5), 30)
+            .scale(3, 2)
+            .translate(-4, 7);
+        let inverse = transform.inverse().unwrap();
+        for point in [(0, 0), (5, 5), (-20, 13), (31, 31)] {
+            assert_eq!(inverse.apply(transform.apply(point)), point);
+        }
+
+        assert!(Transform::new().scale(0, 1).inverse().is_none());
+        assert_eq!(Transform::new().then(turn), turn);
+    }
+
+    #[test]
+    fn test_exact_shapes() {
+        let mut buffer = [0; 32 * 4];
+        let mut expected_buffer = [0; 32 * 4];
+        let mut framebuffer = Framebuffer::new(32, 32, &mut buffer).unwrap();
+        let mut expected = Framebuffer::new(32, 32, &mut expected_buffer).unwrap();
+
+        // A quarter turn of a rectangle is the same rectangle standing up
+        let rect = Rect::new_filled(BBox::new((10, 14), (21, 17)), Color::Black);
+        Transformed::new(rect, Transform::new().rotate_around((15, 15), 90)).draw(&mut framebuffer);
+        Rect::new_filled(BBox::new((14, 9), (17, 20)), Color::Black).draw(&mut expected);
+        assert!(same_frames(&framebuffer, &expected));
+
+        // Points of a polygon are moved, the outline stays one pixel thin
+        let points = [(2, 2), (12, 2), (2, 8)];
+        let triangle = Polygon::new(&points, Color::Black);
+        let flip = Transform::new().flip_vertical().translate(0, 31);
+        triangle.draw_transformed(&flip, &mut framebuffer);
+        Polygon::new(&[(2, 28), (12, 28), (2, 22)], Color::Black).draw(&mut expected);
+        assert!(same_frames(&framebuffer, &expected));
+
+        let group = (
+            Line::new(BBox::new((0, 0), (3, 0)), Color::Black),
+            Circle::new((5, 5), 2, Color::Black),
+        );
+        Transformed::new(group, Transform::new().scale(2, 2)).draw(&mut framebuffer);
+        Line::new(BBox::new((0, 0), (7, 0)), Color::Black).draw(&mut expected);
+        Circle::new((10, 10), 4, Color::Black)
+            .thickness(2)
+            .draw(&mut expected);
+        assert!(same_frames(&framebuffer, &expected));
+    }
+
+    #[test]
+    fn test_mixed_group() {
+        let mut buffer = [0; 32 * 4];
+        let mut framebuffer = Framebuffer::new(32, 32, &mut buffer).unwrap();
+
+        // A frame and a bitmap covering the same 4x4 pixels keep matching when scaled
+        let frame = Rect::new(BBox::new((2, 2), (5, 5)), Color::Black);
+        let fill = Bitmap::new(4, 4, &[0xF0; 4], Color::White).at((2, 2));
+        let icon = Transformed::new((fill, frame), Transform::new().scale_around((2, 2), 2, 2));
+        Rect::new_filled(BBox::new((0, 0), (15, 15)), Color::Black).draw(&mut framebuffer);
+        icon.draw(&mut framebuffer);
+
+        for y in 0..16 {
+            for x in 0..16 {
+                let frame = x == 2 || x == 9 || y == 2 || y == 9;
+                let inside = (2..=9).contains(&x) && (2..=9).contains(&y);
+                let black = frame || !inside;
+                assert_eq!(
+                    framebuffer.get_pixel(x, y) == Color::Black,
+                    black,
+                    "{x} {y}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_resampled_bitmap() {
+        let mut buffer = [0; 32 * 4];
+        let mut framebuffer = Framebuffer::new(32, 32, &mut buffer).unwrap();
+
+        // An L of three pixels
+        let icon = Bitmap::new(2, 2, &[0x80, 0xC0], Color::Black).at((4, 4));
+        let transform = Transform::new().scale_around((4, 4), 3, 3);
+        let mut big = Transformed::new(icon, transform);
+        big.draw(&mut framebuffer);
+
+        for y in 4..10 {
+            for x in 4..10 {
+                let set = x < 7 || y >= 7;
+                assert_eq!(framebuffer.get_pixel(x, y) == Color::Black, set, "{x} {y}");
+            }
+        }
+        assert_eq!(framebuffer.get_pixel(10, 9), Color::White);
+        assert_eq!(framebuffer.get_pixel(4, 10), Color::White);
+
+        // Rotation leaves no holes in a solid block
+        let block = Bitmap::new(8, 8, &[0xFF; 8], Color::White).at((12, 12));
+        Rect::new_filled(BBox::new((8, 8), (27, 27)), Color::Black).draw(&mut framebuffer);
+        let turn = Transform::new()
+            .translate(-16, -16)
+            .rotate(45)
+            .translate(16, 16);
+        big = Transformed::new(block, turn);
+        big.draw(&mut framebuffer);
+        for y in 13..19 {
+            for x in 13..19 {
+                assert_eq!(framebuffer.get_pixel(x, y), Color::White, "{x} {y}");
+            }
+        }
+        assert_eq!(framebuffer.get_pixel(16, 11), Color::White);
+        assert_eq!(framebuffer.get_pixel(10, 10), Color::Black);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Size, Vec2};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Bitmap, Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::Widget;
+
+/// Push button with a text label or an icon.
+///
+/// It's pressed while `Enter` or a touch is held and it's clicked on release.
+pub struct Button<'a> {
+    bbox: BBox,
+    label: &'a str,
+    icon: Option<Bitmap<'a>>,
+    pressed: bool,
+    clicked: bool,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> Button<'a> {
+    pub fn new(bbox: BBox, label: &'a str, color: Color) -> Self {
+        Self {
+            bbox,
+            label,
+            icon: None,
+            pressed: false,
+            clicked: false,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Show an icon left to the label, or instead of it if the label is empty.
+    pub fn icon(mut self, icon: Bitmap<'a>) -> Self {
+        self.icon = Some(icon);
+
+        self
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Returns `true` if the button was clicked since the last call.
+    pub fn take_clicked(&mut self) -> bool {
+        core::mem::take(&mut self.clicked)
+    }
+
+    fn set_pressed(&mut self, pressed: bool) {
+        if self.pressed != pressed {
+            self.pressed = pressed;
+            self.dirty = true;
+        }
+    }
+
+    /// Size of the icon and the label with a gap between them.
+    fn content_size(&self) -> Vec2 {
+        let (label_width, label_height) = FONT_5X7.text_size(self.label);
+        let (icon_width, icon_height) = self.icon.map_or((0, 0), |icon| icon.size());
+        let gap = if icon_width > 0 && label_width > 0 {
+            FONT_5X7.spacing as i32 * 2
+        } else {
+            0
+        };
+
+        (
+            icon_width + gap + label_width,
+            icon_height.max(label_height),
+        )
+    }
+}
+
+impl<'a> Drawable for Button<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        let (background, foreground) = if self.pressed {
+            (self.color, self.color.invert())
+        } else {
+            (self.color.invert(), self.color)
+        };
+
+        Rect::new_filled(bbox, background).draw(canvas);
+        Rect::new(bbox, self.color).draw(canvas);
+        if self.focus != Focus::None {
+            let inner = BBox::new(
+                (bbox.start.0 + 1, bbox.start.1 + 1),
+                (bbox.end.0 - 1, bbox.end.1 - 1),
+            );
+            Rect::new(inner, self.color).draw(canvas);
+        }
+
+        let (width, height) = self.content_size();
+        let mut x = bbox.start.0 + (bbox.width() as i32 + 1 - width) / 2;
+        let y = bbox.start.1 + (bbox.height() as i32 + 1 - height) / 2;
+
+        let mut clip = Clip::new(canvas, bbox);
+        if let Some(icon) = self.icon {
+            let icon_y = y + (height - icon.size().1) / 2;
+            icon.at((x, icon_y)).color(foreground).draw(&mut clip);
+            x += icon.size().0 + FONT_5X7.spacing as i32 * 2;
+        }
+
+        let label_y = y + (height - FONT_5X7.height as i32) / 2;
+        Text::new((x, label_y), self.label, foreground).draw(&mut clip);
+    }
+}
+
+impl<'a> Widget for Button<'a> {
+    fn min_size(&self) -> Size {
+        let (width, height) = self.content_size();
+
+        Size::new(width + 3, height + 3)
+    }
+
+    fn preferred_size(&self) -> Size {
+        let (width, height) = self.content_size();
+
+        Size::new(width + 9, height + 5)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        if focus == Focus::None {
+            self.pressed = false;
+        }
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::KeyPress(Key::Enter | Key::EncoderPush) | Event::TouchDown(_) => {
+                self.set_pressed(true);
+            }
+            Event::KeyRelease(Key::Enter | Key::EncoderPush) | Event::TouchUp(_) => {
+                if !self.pressed {
+                    return false;
+                }
+
+                self.set_pressed(false);
+                self.clicked = true;
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
@@ -0,0 +1,556 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::Size;
+use crate::fixed;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Line, Rect};
+use crate::text::{format_number, Text, FONT_5X7, MAX_NUMBER};
+use crate::widgets::Widget;
+
+/// Length of tick marks outside of the axes.
+const TICK_LENGTH: i32 = 2;
+/// Gap between bars of a `BarChart`.
+const BAR_GAP: i32 = 1;
+
+/// Samples plotted by a chart. None of the variants needs an allocation.
+#[derive(Clone, Copy)]
+pub enum Series<'a> {
+    Slice(&'a [i32]),
+    /// Ring buffer and the index of its oldest sample. Samples are plotted from the oldest.
+    Ring(&'a [i32], usize),
+}
+
+impl<'a> Series<'a> {
+    pub fn len(&self) -> usize {
+        match self {
+            Series::Slice(data) | Series::Ring(data, _) => data.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sample with the `index`, counting from the oldest one.
+    pub fn get(&self, index: usize) -> Option<i32> {
+        match *self {
+            Series::Slice(data) => data.get(index).copied(),
+            Series::Ring(data, oldest) if index < data.len() => {
+                Some(data[(oldest % data.len() + index) % data.len()])
+            }
+            Series::Ring(..) => None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = i32> + 'a {
+        let series = *self;
+        (0..series.len()).filter_map(move |index| series.get(index))
+    }
+
+    /// The smallest and the biggest samples.
+    pub fn range(&self) -> Option<(i32, i32)> {
+        self.iter().fold(None, |range, value| match range {
+            None => Some((value, value)),
+            Some((min, max)) => Some((min.min(value), max.max(value))),
+        })
+    }
+}
+
+/// Value range of an axis, extended to multiples of a round tick step.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct Scale {
+    pub min: i32,
+    pub max: i32,
+    /// Distance between ticks: 1, 2 or 5 multiplied by a power of ten
+    pub step: i32,
+}
+
+impl Scale {
+    /// Scale which covers `min..=max` with at most `max_ticks` steps.
+    pub fn new(min: i32, max: i32, max_ticks: u32) -> Self {
+        let (min, max) = (min.min(max) as i64, min.max(max) as i64);
+        let range = (max - min).max(1);
+        let ticks = max_ticks.max(1) as i64;
+        let raw = (range + ticks - 1) / ticks;
+
+        let mut magnitude = 1;
+        let step = 'search: loop {
+            for multiplier in [1, 2, 5] {
+                if multiplier * magnitude >= raw {
+                    break 'search multiplier * magnitude;
+                }
+            }
+            magnitude *= 10;
+        };
+
+        let low = min.div_euclid(step) * step;
+        let high = (max + step - 1).div_euclid(step) * step;
+        let high = if high == low { low + step } else { high };
+        let clamp = |value: i64| value.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+
+        Self {
+            min: clamp(low),
+            max: clamp(high),
+            step: clamp(step),
+        }
+    }
+
+    /// Pixel coordinate of the `value` on an axis which goes from `start` for the minimum
+    /// to `end` for the maximum.
+    pub fn map(&self, value: i32, start: i32, end: i32) -> i32 {
+        fixed::map(value, (self.min, self.max), (start, end))
+    }
+
+    /// Values of all ticks from the minimum to the maximum.
+    pub fn ticks(&self) -> impl Iterator<Item = i32> {
+        let (min, max, step) = (self.min as i64, self.max as i64, self.step.max(1) as i64);
+        let count = (max - min) / step + 1;
+
+        (0..count).map(move |index| (min + index * step) as i32)
+    }
+}
+
+/// Axes shared by `LineChart` and `BarChart`: the value axis on the left with ticks
+/// and labels, and the time axis at the bottom.
+#[derive(Clone, Copy)]
+struct Axes {
+    bbox: BBox,
+    range: Option<(i32, i32)>,
+    labels: bool,
+    x_ticks: usize,
+}
+
+impl Axes {
+    fn scale(&self, series: &Series<'_>) -> Scale {
+        let (min, max) = self.range.or(series.range()).unwrap_or((0, 1));
+        let height = self.bbox.end.1 - self.bbox.start.1;
+        let spacing = if self.labels {
+            FONT_5X7.height as i32 + 2
+        } else {
+            4
+        };
+
+        let scale = Scale::new(min, max, (height / spacing).max(1) as u32);
+        if !self.labels {
+            return scale;
+        }
+
+        // The plot is shorter than the widget, but label width depends on the scale
+        let plot = self.plot(&scale);
+        Scale::new(
+            min,
+            max,
+            ((plot.end.1 - plot.start.1) / spacing).max(1) as u32,
+        )
+    }
+
+    fn label_width(&self, scale: &Scale) -> i32 {
+        if !self.labels {
+            return 0;
+        }
+
+        let mut buffer = [0; MAX_NUMBER];
+        let chars = scale
+            .ticks()
+            .map(|tick| format_number(tick, &mut buffer).len())
+            .max()
+            .unwrap_or(0) as u32;
+
+        (chars * FONT_5X7.advance()) as i32
+    }
+
+    /// Area inside the axes.
+    fn plot(&self, scale: &Scale) -> BBox {
+        let bbox = self.bbox;
+        // Labels of the top and bottom ticks stick out of the plot by half of their height
+        let overhang = if self.labels {
+            FONT_5X7.height as i32 / 2
+        } else {
+            0
+        };
+        let bottom = if self.x_ticks > 0 { TICK_LENGTH } else { 0 };
+        let left = bbox.start.0 + self.label_width(scale) + TICK_LENGTH + 1;
+
+        BBox::new(
+            (left, bbox.start.1 + overhang),
+            (bbox.end.0, bbox.end.1 - overhang.max(bottom) - 1),
+        )
+    }
+
+    /// Draw axes, ticks and labels.
+    fn draw<C: PixelDraw>(&self, canvas: &mut C, scale: &Scale, samples: usize, color: Color) {
+        // Labels stick out of small charts
+        let canvas = &mut Clip::new(canvas, self.bbox);
+        let plot = self.plot(scale);
+        let (axis_x, axis_y) = (plot.start.0 - 1, plot.end.1 + 1);
+
+        Line::new_vertical(BBox::new((axis_x, plot.start.1), (axis_x, axis_y)), color).draw(canvas);
+        Line::new(BBox::new((axis_x, axis_y), (plot.end.0, axis_y)), color).draw(canvas);
+
+        let mut buffer = [0; MAX_NUMBER];
+        for tick in scale.ticks() {
+            let y = scale.map(tick, plot.end.1, plot.start.1);
+            Line::new(BBox::new((axis_x - TICK_LENGTH, y), (axis_x - 1, y)), color).draw(canvas);
+
+            if self.labels {
+                let label = format_number(tick, &mut buffer);
+                let area = BBox::new(
+                    (self.bbox.start.0, y - FONT_5X7.height as i32 / 2),
+                    (axis_x - TICK_LENGTH - 1, y + FONT_5X7.height as i32 / 2),
+                );
+                Text::new((0, 0), label, color)
+                    .align(area, Alignment::End, Alignment::Center)
+                    .draw(canvas);
+            }
+        }
+
+        if self.x_ticks > 0 && samples > 1 {
+            for index in (0..samples).step_by(self.x_ticks) {
+                let x = sample_x(plot, index, samples);
+                Line::new_vertical(BBox::new((x, axis_y + 1), (x, axis_y + TICK_LENGTH)), color)
+                    .draw(canvas);
+            }
+        }
+    }
+}
+
+/// Horizontal position of the sample, the first and the last ones are at the edges.
+fn sample_x(plot: BBox, index: usize, samples: usize) -> i32 {
+    if samples < 2 {
+        return plot.start.0;
+    }
+
+    let width = (plot.end.0 - plot.start.0).max(0) as i64;
+    plot.start.0 + (width * index as i64 / (samples - 1) as i64) as i32
+}
+
+/// Samples connected with lines over autoscaled axes with tick marks.
+///
+/// The value axis covers the samples with round ticks unless the range is fixed.
+/// Samples are spread over the whole width, the oldest one is on the left.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::{Color, Framebuffer};
+/// # use rugui::geometry::Drawable;
+/// # use rugui::widgets::{LineChart, Series};
+/// # let mut buffer = [0; 128 * 8];
+/// # let mut framebuffer = Framebuffer::new(128, 64, &mut buffer).unwrap();
+/// let temperature = [21, 22, 24, 23, 25, 27, 26];
+/// LineChart::new(BBox::new((0, 0), (127, 63)), Series::Slice(&temperature), Color::Black)
+///     .x_ticks(2)
+///     .draw(&mut framebuffer);
+/// ```
+pub struct LineChart<'a> {
+    series: Series<'a>,
+    axes: Axes,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> LineChart<'a> {
+    pub fn new(bbox: BBox, series: Series<'a>, color: Color) -> Self {
+        Self {
+            series,
+            axes: Axes {
+                bbox,
+                range: None,
+                labels: true,
+                x_ticks: 0,
+            },
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Fixed range of the value axis instead of the range of samples.
+    pub fn range(mut self, min: i32, max: i32) -> Self {
+        self.axes.range = Some((min, max));
+
+        self
+    }
+
+    /// Show values of the ticks on the value axis. Enabled by default.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.axes.labels = labels;
+
+        self
+    }
+
+    /// Put a tick on the time axis every `every` samples. Zero disables ticks.
+    pub fn x_ticks(mut self, every: usize) -> Self {
+        self.axes.x_ticks = every;
+
+        self
+    }
+
+    /// Replace the samples, e.g. after a new reading was added to the ring buffer.
+    pub fn set_series(&mut self, series: Series<'a>) {
+        self.series = series;
+        self.dirty = true;
+    }
+
+    pub fn scale(&self) -> Scale {
+        self.axes.scale(&self.series)
+    }
+}
+
+impl<'a> Drawable for LineChart<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        Rect::new_filled(self.axes.bbox, self.color.invert()).draw(canvas);
+
+        let scale = self.scale();
+        let samples = self.series.len();
+        self.axes.draw(canvas, &scale, samples, self.color);
+
+        let plot = self.axes.plot(&scale);
+        let point = |index: usize, value: i32| {
+            (
+                sample_x(plot, index, samples),
+                scale.map(value, plot.end.1, plot.start.1),
+            )
+        };
+
+        let mut clip = Clip::new(canvas, plot);
+        let mut previous = None;
+        for (index, value) in self.series.iter().enumerate() {
+            let current = point(index, value);
+            let start = previous.unwrap_or(current);
+            Line::new(BBox::new(start, current), self.color).draw(&mut clip);
+            previous = Some(current);
+        }
+    }
+}
+
+impl<'a> Widget for LineChart<'a> {
+    fn min_size(&self) -> Size {
+        let label = self.axes.label_width(&self.scale());
+
+        Size::new(label + TICK_LENGTH + 8, FONT_5X7.height as i32 * 2 + 2)
+    }
+
+    fn preferred_size(&self) -> Size {
+        Size::new(127, 47)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.axes.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.axes.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
+
+/// A bar for every sample over autoscaled axes. Bars grow from zero, so negative
+/// samples go down when the range includes zero.
+pub struct BarChart<'a> {
+    series: Series<'a>,
+    axes: Axes,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> BarChart<'a> {
+    pub fn new(bbox: BBox, series: Series<'a>, color: Color) -> Self {
+        Self {
+            series,
+            axes: Axes {
+                bbox,
+                range: None,
+                labels: true,
+                x_ticks: 0,
+            },
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Fixed range of the value axis instead of the range of samples.
+    pub fn range(mut self, min: i32, max: i32) -> Self {
+        self.axes.range = Some((min, max));
+
+        self
+    }
+
+    /// Show values of the ticks on the value axis. Enabled by default.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.axes.labels = labels;
+
+        self
+    }
+
+    pub fn set_series(&mut self, series: Series<'a>) {
+        self.series = series;
+        self.dirty = true;
+    }
+
+    /// Bars always start at zero, so it is included in the automatic range.
+    pub fn scale(&self) -> Scale {
+        let mut axes = self.axes;
+        if axes.range.is_none() {
+            let (min, max) = self.series.range().unwrap_or((0, 1));
+            axes.range = Some((min.min(0), max.max(0)));
+        }
+
+        axes.scale(&self.series)
+    }
+}
+
+impl<'a> Drawable for BarChart<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        Rect::new_filled(self.axes.bbox, self.color.invert()).draw(canvas);
+
+        let scale = self.scale();
+        self.axes.draw(canvas, &scale, 0, self.color);
+
+        let plot = self.axes.plot(&scale);
+        let samples = self.series.len() as i32;
+        if samples == 0 {
+            return;
+        }
+
+        let width = (plot.end.0 - plot.start.0 + 1).max(0);
+        let base = scale.map(0, plot.end.1, plot.start.1);
+
+        let mut clip = Clip::new(canvas, plot);
+        for (index, value) in self.series.iter().enumerate() {
+            let index = index as i32;
+            let left = plot.start.0 + width * index / samples;
+            let right = plot.start.0 + width * (index + 1) / samples - 1;
+            let right = if right - left > BAR_GAP {
+                right - BAR_GAP
+            } else {
+                right
+            };
+
+            let top = scale.map(value, plot.end.1, plot.start.1);
+            let bar = BBox::new((left, top.min(base)), (right, top.max(base)));
+            Rect::new_filled(bar, self.color).draw(&mut clip);
+        }
+    }
+}
+
+impl<'a> Widget for BarChart<'a> {
+    fn min_size(&self) -> Size {
+        let label = self.axes.label_width(&self.scale());
+
+        Size::new(label + TICK_LENGTH + 8, FONT_5X7.height as i32 * 2 + 2)
+    }
+
+    fn preferred_size(&self) -> Size {
+        Size::new(127, 47)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.axes.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.axes.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
+
+/// Compact line chart without axes which fills its bounding box, e.g. next to a reading.
+pub struct Sparkline<'a> {
+    bbox: BBox,
+    series: Series<'a>,
+    range: Option<(i32, i32)>,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> Sparkline<'a> {
+    pub fn new(bbox: BBox, series: Series<'a>, color: Color) -> Self {
+        Self {
+            bbox,
+            series,
+            range: None,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Fixed range instead of the range of samples.
+    pub fn range(mut self, min: i32, max: i32) -> Self {
+        self.range = Some((min, max));
+
+        self
+    }
+
+    pub fn set_series(&mut self, series: Series<'a>) {
+        self.series = series;
+        self.dirty = true;
+    }
+}
+
+impl<'a> Drawable for Sparkline<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+
+        let (min, max) = self.range.or(self.series.range()).unwrap_or((0, 1));
+        // Exact range, so the line uses the whole height
+        let scale = Scale {
+            min: min.min(max),
+            max: max.max(min),
+            step: 1,
+        };
+        let samples = self.series.len();
+
+        let mut clip = Clip::new(canvas, bbox);
+        let mut previous = None;
+        for (index, value) in self.series.iter().enumerate() {
+            let current = (
+                sample_x(bbox, index, samples),
+                scale.map(value, bbox.end.1, bbox.start.1),
+            );
+            Line::new(BBox::new(previous.unwrap_or(current), current), self.color).draw(&mut clip);
+            previous = Some(current);
+        }
+    }
+}
+
+impl<'a> Widget for Sparkline<'a> {
+    fn min_size(&self) -> Size {
+        Size::new(1, 1)
+    }
+
+    fn preferred_size(&self) -> Size {
+        Size::new(31, 7)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
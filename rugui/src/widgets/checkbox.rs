@@ -0,0 +1,268 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Size, Vec2};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Circle, Drawable, Line, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::Widget;
+
+/// Size of the check box and the toggle knob in pixels.
+const BOX_SIZE: i32 = FONT_5X7.height as i32 + 2;
+/// Gap between the control and its label.
+const LABEL_GAP: i32 = 4;
+
+/// Check box with a label. `Enter` or a tap toggles it.
+pub struct Checkbox<'a> {
+    bbox: BBox,
+    label: &'a str,
+    checked: bool,
+    changed: bool,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+/// Toggle switch with a label. `Enter` or a tap toggles it.
+pub struct Toggle<'a> {
+    checkbox: Checkbox<'a>,
+}
+
+impl<'a> Checkbox<'a> {
+    pub fn new(bbox: BBox, label: &'a str, checked: bool, color: Color) -> Self {
+        Self {
+            bbox,
+            label,
+            checked,
+            changed: false,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        }
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    pub fn set_checked(&mut self, checked: bool) {
+        if self.checked != checked {
+            self.checked = checked;
+            self.dirty = true;
+        }
+    }
+
+    /// Returns the new state if it was changed by the user since the last call.
+    pub fn take_changed(&mut self) -> Option<bool> {
+        if core::mem::take(&mut self.changed) {
+            Some(self.checked)
+        } else {
+            None
+        }
+    }
+
+    /// Bounding box of the control, vertically centered at the start of the widget.
+    fn control(&self, width: i32) -> BBox {
+        let y = self.bbox.start.1 + (self.bbox.height() as i32 + 1 - BOX_SIZE) / 2;
+
+        BBox::from_relative((self.bbox.start.0, y), (width - 1, BOX_SIZE - 1))
+    }
+
+    fn draw_label<C: PixelDraw>(&self, canvas: &mut C, control: BBox) {
+        Rect::new_filled(self.bbox, self.color.invert()).draw(canvas);
+
+        let label = BBox::new(
+            (control.end.0 + LABEL_GAP, self.bbox.start.1),
+            self.bbox.end,
+        );
+        let mut clip = Clip::new(canvas, label);
+        Text::new(label.start, self.label, self.color)
+            .align(label, Alignment::Start, Alignment::Center)
+            .draw(&mut clip);
+
+        // Focus is shown by underlining the label
+        if self.focus != Focus::None {
+            let y = control.end.1;
+            let end = label.start.0 + FONT_5X7.text_size(self.label).0 - 1;
+            Line::new(BBox::new((label.start.0, y), (end, y)), self.color).draw(canvas);
+        }
+    }
+
+    fn size(&self, control_width: i32) -> Vec2 {
+        let (width, height) = FONT_5X7.text_size(self.label);
+
+        (
+            control_width + LABEL_GAP + width - 1,
+            height.max(BOX_SIZE) - 1,
+        )
+    }
+
+    fn toggle(&mut self) {
+        self.checked = !self.checked;
+        self.changed = true;
+        self.dirty = true;
+    }
+}
+
+impl<'a> Drawable for Checkbox<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let control = self.control(BOX_SIZE);
+        self.draw_label(canvas, control);
+
+        Rect::new(control, self.color).draw(canvas);
+        if self.checked {
+            let (start, end) = (control.start, control.end);
+            Line::new(
+                BBox::new((start.0 + 2, start.1 + 2), (end.0 - 2, end.1 - 2)),
+                self.color,
+            )
+            .draw(canvas);
+            Line::new(
+                BBox::new((end.0 - 2, start.1 + 2), (start.0 + 2, end.1 - 2)),
+                self.color,
+            )
+            .draw(canvas);
+        }
+    }
+}
+
+impl<'a> Toggle<'a> {
+    pub fn new(bbox: BBox, label: &'a str, on: bool, color: Color) -> Self {
+        Self {
+            checkbox: Checkbox::new(bbox, label, on, color),
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.checkbox.checked
+    }
+
+    pub fn set_on(&mut self, on: bool) {
+        self.checkbox.set_checked(on);
+    }
+
+    /// Returns the new state if it was changed by the user since the last call.
+    pub fn take_changed(&mut self) -> Option<bool> {
+        self.checkbox.take_changed()
+    }
+}
+
+impl<'a> Drawable for Toggle<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let checkbox = &self.checkbox;
+        let track = checkbox.control(BOX_SIZE * 2);
+        checkbox.draw_label(canvas, track);
+
+        let r = BOX_SIZE / 2;
+        let (left, right) = track.split(Axis::X, track.start.0 + BOX_SIZE);
+        let knob = if checkbox.checked { right } else { left };
+
+        // Rounded track: two half circles with straight lines between them
+        let straight = BBox::new(
+            (left.start.0 + r, track.start.1),
+            (right.end.0 - r, track.end.1),
+        );
+        let (top, bottom) = (straight.start.1, straight.end.1);
+        let (start, end) = (straight.start.0, straight.end.0);
+        Circle::from_bbox(left, checkbox.color)
+            .filled(checkbox.checked)
+            .draw(canvas);
+        Circle::from_bbox(right, checkbox.color)
+            .filled(checkbox.checked)
+            .draw(canvas);
+        if checkbox.checked {
+            Rect::new_filled(straight, checkbox.color).draw(canvas);
+        } else {
+            Rect::new_filled(straight, checkbox.color.invert()).draw(canvas);
+            Line::new(BBox::new((start, top), (end, top)), checkbox.color).draw(canvas);
+            Line::new(BBox::new((start, bottom), (end, bottom)), checkbox.color).draw(canvas);
+        }
+
+        let knob = BBox::new(
+            (knob.start.0 + 2, knob.start.1 + 2),
+            (knob.end.0 - 2, knob.end.1 - 2),
+        );
+        let color = if checkbox.checked {
+            checkbox.color.invert()
+        } else {
+            checkbox.color
+        };
+        Circle::from_bbox(knob, color).filled(true).draw(canvas);
+    }
+}
+
+impl<'a> Widget for Checkbox<'a> {
+    fn min_size(&self) -> Size {
+        self.size(BOX_SIZE).into()
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::KeyPress(Key::Enter | Key::EncoderPush) | Event::TouchDown(_) => {
+                self.toggle();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
+
+impl<'a> Widget for Toggle<'a> {
+    fn min_size(&self) -> Size {
+        self.checkbox.size(BOX_SIZE * 2).into()
+    }
+
+    fn bbox(&self) -> BBox {
+        self.checkbox.bbox()
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.checkbox.set_bbox(bbox);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.checkbox.set_focus(focus);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.checkbox.handle_event(event)
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.checkbox.needs_redraw()
+    }
+
+    fn mark_drawn(&mut self) {
+        self.checkbox.mark_drawn();
+    }
+}
@@ -0,0 +1,275 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Size, Vec2};
+use crate::fixed::{map, polar};
+use crate::framebuffer::*;
+use crate::geometry::{Circle, Drawable, Line, Polygon, Rect};
+use crate::text::{format_number, Text, FONT_5X7, MAX_NUMBER};
+use crate::widgets::Widget;
+
+/// Length of major ticks, minor ones are half as long.
+const TICK_LENGTH: i32 = 4;
+/// Width of a zone band along the scale.
+const ZONE_WIDTH: i32 = 2;
+/// Radius of the needle hub.
+const HUB_RADIUS: u32 = 2;
+/// Distance from the needle axis to the corners of its base.
+const NEEDLE_HALF_WIDTH: i32 = 1;
+
+/// Shape of the gauge scale.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum GaugeShape {
+    /// Dial with the scale of 270 degrees and a gap at the bottom.
+    Circle,
+    /// Half of a dial, from left to right with the center at the bottom.
+    Semicircle,
+}
+
+impl GaugeShape {
+    /// Angles of the minimum and the maximum in degrees, counterclockwise from 3 o'clock.
+    fn sweep(&self) -> (i32, i32) {
+        match self {
+            GaugeShape::Circle => (225, -45),
+            GaugeShape::Semicircle => (180, 0),
+        }
+    }
+}
+
+/// Part of the scale highlighted with a band, e.g. the red zone of a tachometer.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct Zone {
+    pub from: i32,
+    pub to: i32,
+    /// Monochrome displays only show zones of the gauge color.
+    pub color: Color,
+}
+
+/// Analog gauge with a scale, tick marks and a needle.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::{Color, Framebuffer};
+/// # use rugui::geometry::Drawable;
+/// # use rugui::widgets::{Gauge, GaugeShape, Zone};
+/// # let mut buffer = [0; 64 * 4];
+/// # let mut framebuffer = Framebuffer::new(64, 32, &mut buffer).unwrap();
+/// let zones = [Zone { from: 80, to: 100, color: Color::Black }];
+/// Gauge::new(BBox::new((0, 0), (63, 31)), 0, 100, 42, Color::Black)
+///     .shape(GaugeShape::Semicircle)
+///     .ticks(5, 1)
+///     .zones(&zones)
+///     .draw(&mut framebuffer);
+/// ```
+pub struct Gauge<'a> {
+    bbox: BBox,
+    min: i32,
+    max: i32,
+    value: i32,
+    shape: GaugeShape,
+    major: u32,
+    minor: u32,
+    zones: &'a [Zone],
+    label: bool,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> Gauge<'a> {
+    pub fn new(bbox: BBox, min: i32, max: i32, value: i32, color: Color) -> Self {
+        let max = max.max(min);
+
+        Self {
+            bbox,
+            min,
+            max,
+            value: value.clamp(min, max),
+            shape: GaugeShape::Circle,
+            major: 4,
+            minor: 0,
+            zones: &[],
+            label: false,
+            color,
+            dirty: true,
+        }
+    }
+
+    pub fn shape(mut self, shape: GaugeShape) -> Self {
+        self.shape = shape;
+
+        self
+    }
+
+    /// Split the scale into `major` intervals, each one split by `minor` smaller ticks.
+    pub fn ticks(mut self, major: u32, minor: u32) -> Self {
+        self.major = major;
+        self.minor = minor;
+
+        self
+    }
+
+    pub fn zones(mut self, zones: &'a [Zone]) -> Self {
+        self.zones = zones;
+
+        self
+    }
+
+    /// Show the value as a number below the needle hub.
+    pub fn label(mut self, label: bool) -> Self {
+        self.label = label;
+
+        self
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: i32) {
+        let value = value.clamp(self.min, self.max);
+        if self.value != value {
+            self.value = value;
+            self.dirty = true;
+        }
+    }
+
+    /// Center and radius of the scale.
+    fn geometry(&self) -> (Vec2, i32) {
+        let bbox = self.bbox;
+        let label = if self.label {
+            FONT_5X7.height as i32 + 1
+        } else {
+            0
+        };
+        let center_x = bbox.start.0 + bbox.width() as i32 / 2;
+
+        match self.shape {
+            GaugeShape::Circle => {
+                let radius = (bbox.width().min(bbox.height()) / 2) as i32;
+                ((center_x, bbox.start.1 + radius), radius)
+            }
+            GaugeShape::Semicircle => {
+                let bottom = bbox.end.1 - label;
+                let radius = (bbox.width() as i32 / 2).min(bottom - bbox.start.1);
+                ((center_x, bottom), radius)
+            }
+        }
+    }
+
+    /// Angle of the value on the scale in degrees.
+    fn angle(&self, value: i32) -> i32 {
+        map(value, (self.min, self.max), self.shape.sweep())
+    }
+
+    fn draw_scale<C: PixelDraw>(&self, canvas: &mut C, center: Vec2, radius: i32) {
+        let circle = Circle::new(center, radius.max(0) as u32, self.color);
+        match self.shape {
+            GaugeShape::Circle => circle.draw(canvas),
+            GaugeShape::Semicircle => {
+                let top = BBox::new(
+                    (center.0 - radius, center.1 - radius),
+                    (center.0 + radius, center.1),
+                );
+                circle.draw(&mut Clip::new(canvas, top));
+            }
+        }
+
+        // Zones are drawn as short radial lines for every degree of the band
+        for zone in self.zones {
+            let (from, to) = (self.angle(zone.from), self.angle(zone.to));
+            for angle in from.min(to)..=from.max(to) {
+                let outer = polar(center, radius - 2, angle);
+                let inner = polar(center, radius - 1 - ZONE_WIDTH, angle);
+                Line::new(BBox::new(outer, inner), zone.color).draw(canvas);
+            }
+        }
+
+        let (start, end) = self.shape.sweep();
+        let ticks = self.major.max(1) as i32 * (self.minor as i32 + 1);
+        for tick in 0..=ticks {
+            let angle = map(tick, (0, ticks), (start, end));
+            let major = tick % (self.minor as i32 + 1) == 0;
+            let length = if major { TICK_LENGTH } else { TICK_LENGTH / 2 };
+
+            let outer = polar(center, radius - 1, angle);
+            let inner = polar(center, radius - length, angle);
+            Line::new(BBox::new(outer, inner), self.color).draw(canvas);
+        }
+    }
+
+    fn draw_needle<C: PixelDraw>(&self, canvas: &mut C, center: Vec2, radius: i32) {
+        let angle = self.angle(self.value);
+        let needle = [
+            polar(center, radius - TICK_LENGTH - 1, angle),
+            polar(center, NEEDLE_HALF_WIDTH, angle + 90),
+            polar(center, HUB_RADIUS as i32 + 1, angle + 180),
+            polar(center, NEEDLE_HALF_WIDTH, angle - 90),
+        ];
+
+        Polygon::new(&needle, self.color).filled(true).draw(canvas);
+        Circle::new(center, HUB_RADIUS, self.color)
+            .filled(true)
+            .draw(canvas);
+    }
+
+    fn draw_label<C: PixelDraw>(&self, canvas: &mut C, center: Vec2) {
+        let mut buffer = [0; MAX_NUMBER];
+        let label = format_number(self.value, &mut buffer);
+        let top = match self.shape {
+            GaugeShape::Circle => center.1 + HUB_RADIUS as i32 + 3,
+            GaugeShape::Semicircle => center.1 + 2,
+        };
+        let area = BBox::new(
+            (self.bbox.start.0, top),
+            (self.bbox.end.0, top + FONT_5X7.height as i32 - 1),
+        );
+        Text::new((0, 0), label, self.color)
+            .align(area, Alignment::Center, Alignment::Start)
+            .draw(canvas);
+    }
+}
+
+impl<'a> Drawable for Gauge<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        Rect::new_filled(self.bbox, self.color.invert()).draw(canvas);
+
+        let (center, radius) = self.geometry();
+        let mut clip = Clip::new(canvas, self.bbox);
+        self.draw_scale(&mut clip, center, radius);
+        self.draw_needle(&mut clip, center, radius);
+        if self.label {
+            self.draw_label(&mut clip, center);
+        }
+    }
+}
+
+impl<'a> Widget for Gauge<'a> {
+    fn min_size(&self) -> Size {
+        match self.shape {
+            GaugeShape::Circle => Size::new(16, 16),
+            GaugeShape::Semicircle => Size::new(16, 8),
+        }
+    }
+
+    fn preferred_size(&self) -> Size {
+        match self.shape {
+            GaugeShape::Circle => Size::new(47, 47),
+            GaugeShape::Semicircle => Size::new(63, 31),
+        }
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
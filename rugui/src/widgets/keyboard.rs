@@ -0,0 +1,392 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Point, Size, Vec2};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::Widget;
+
+/// Number of columns of every layout.
+const COLUMNS: usize = 10;
+/// Rows of characters, the special keys are in the row below them.
+const CHAR_ROWS: usize = 3;
+/// Keys of the bottom row and the number of columns they span.
+const SPECIAL_KEYS: [(Special, usize); 5] = [
+    (Special::Shift, 2),
+    (Special::Mode, 2),
+    (Special::Space, 3),
+    (Special::Backspace, 1),
+    (Special::Done, 2),
+];
+/// Number of all keys.
+const KEYS: usize = COLUMNS * CHAR_ROWS + SPECIAL_KEYS.len();
+
+/// Set of characters shown by a `Keyboard`. Together they cover the printable ASCII.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum KeyboardLayout {
+    Lowercase,
+    Uppercase,
+    Digits,
+    Symbols,
+}
+
+impl KeyboardLayout {
+    fn rows(&self) -> [&'static str; CHAR_ROWS] {
+        match self {
+            KeyboardLayout::Lowercase => ["qwertyuiop", "asdfghjkl'", "zxcvbnm,.?"],
+            KeyboardLayout::Uppercase => ["QWERTYUIOP", "ASDFGHJKL\"", "ZXCVBNM;:!"],
+            KeyboardLayout::Digits => ["1234567890", "-/:;()$&@\"", ".,?!'+=*#%"],
+            KeyboardLayout::Symbols => ["[]{}^_\\|~`", "<>+=*#%$&@", ".,?!'\":;-/"],
+        }
+    }
+}
+
+/// What was typed on a `Keyboard`, see `TextInput::apply`.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum KeyboardInput {
+    Char(char),
+    Backspace,
+    /// The `OK` key
+    Done,
+}
+
+/// Keys of the bottom row.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum Special {
+    /// Switches between the cases, or back to letters
+    Shift,
+    /// Switches between digits and symbols
+    Mode,
+    Space,
+    Backspace,
+    Done,
+}
+
+/// On-screen keyboard for devices with keys, an encoder or a touch panel.
+///
+/// Characters are laid out in a grid of three rows by ten, special keys are in the bottom
+/// row: case switch, digits and symbols switch, space, backspace and `OK`. Arrows move
+/// the selection in the grid, the encoder goes through all keys one by one, `Enter`
+/// types the selected key. A tap types the key under it once released.
+///
+/// In a `FocusChain`, `Enter` captures the input first and `Back` releases it. Typed
+/// input is polled with `take_input`.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::focus::Focus;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::input::{Event, Key};
+/// # use rugui::widgets::{Keyboard, KeyboardInput, Widget};
+/// let mut keyboard = Keyboard::new(BBox::new((0, 14), (127, 63)), Color::Black);
+///
+/// keyboard.set_focus(Focus::Editing);
+/// keyboard.handle_event(&Event::KeyPress(Key::Down));
+/// keyboard.handle_event(&Event::KeyPress(Key::Enter));
+/// assert_eq!(keyboard.take_input(), Some(KeyboardInput::Char('a')));
+/// ```
+pub struct Keyboard {
+    bbox: BBox,
+    layout: KeyboardLayout,
+    /// Index of the selected key, characters row by row and then the special keys
+    selected: usize,
+    /// The selected key is held by a touch
+    pressed: bool,
+    input: Option<KeyboardInput>,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+impl Keyboard {
+    pub fn new(bbox: BBox, color: Color) -> Self {
+        Self {
+            bbox,
+            layout: KeyboardLayout::Lowercase,
+            selected: 0,
+            pressed: false,
+            input: None,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        }
+    }
+
+    pub fn layout(mut self, layout: KeyboardLayout) -> Self {
+        self.layout = layout;
+
+        self
+    }
+
+    pub fn current_layout(&self) -> KeyboardLayout {
+        self.layout
+    }
+
+    pub fn set_layout(&mut self, layout: KeyboardLayout) {
+        if self.layout != layout {
+            self.layout = layout;
+            self.dirty = true;
+        }
+    }
+
+    /// Returns what was typed since the last call.
+    pub fn take_input(&mut self) -> Option<KeyboardInput> {
+        self.input.take()
+    }
+
+    /// Row and column of the key with the `index`.
+    fn position(index: usize) -> (usize, usize) {
+        if index < COLUMNS * CHAR_ROWS {
+            return (index / COLUMNS, index % COLUMNS);
+        }
+
+        let special = index - COLUMNS * CHAR_ROWS;
+        let column = SPECIAL_KEYS[..special].iter().map(|(_, span)| span).sum();
+
+        (CHAR_ROWS, column)
+    }
+
+    /// Index of the key which covers the row and the column.
+    fn index(row: usize, column: usize) -> usize {
+        if row < CHAR_ROWS {
+            return row * COLUMNS + column;
+        }
+
+        let mut end = 0;
+        for (special, (_, span)) in SPECIAL_KEYS.iter().enumerate() {
+            end += span;
+            if column < end {
+                return COLUMNS * CHAR_ROWS + special;
+            }
+        }
+
+        KEYS - 1
+    }
+
+    /// Size of a key cell and the top left corner of the grid.
+    fn grid(&self) -> (Vec2, Vec2) {
+        let width = self.bbox.end.0 - self.bbox.start.0 + 1;
+        let height = self.bbox.end.1 - self.bbox.start.1 + 1;
+        let cell = (width / COLUMNS as i32, height / (CHAR_ROWS as i32 + 1));
+        let start = (
+            self.bbox.start.0 + (width - cell.0 * COLUMNS as i32) / 2,
+            self.bbox.start.1 + (height - cell.1 * (CHAR_ROWS as i32 + 1)) / 2,
+        );
+
+        (cell, start)
+    }
+
+    fn key_bbox(&self, index: usize) -> BBox {
+        let (cell, start) = self.grid();
+        let (row, column) = Self::position(index);
+        let span = match index.checked_sub(COLUMNS * CHAR_ROWS) {
+            Some(special) => SPECIAL_KEYS[special].1 as i32,
+            None => 1,
+        };
+
+        BBox::from_relative(
+            (
+                start.0 + column as i32 * cell.0,
+                start.1 + row as i32 * cell.1,
+            ),
+            (span * cell.0 - 1, cell.1 - 1),
+        )
+    }
+
+    /// Key under the point.
+    fn key_at(&self, Point { x, y }: Point) -> Option<usize> {
+        let (cell, start) = self.grid();
+        if x < start.0 || y < start.1 || cell.0 <= 0 || cell.1 <= 0 {
+            return None;
+        }
+
+        let column = ((x - start.0) / cell.0) as usize;
+        let row = ((y - start.1) / cell.1) as usize;
+        if column >= COLUMNS || row > CHAR_ROWS {
+            return None;
+        }
+
+        Some(Self::index(row, column))
+    }
+
+    fn label(&self, index: usize) -> &'static str {
+        let special = match index.checked_sub(COLUMNS * CHAR_ROWS) {
+            Some(special) => SPECIAL_KEYS[special].0,
+            None => {
+                let (row, column) = Self::position(index);
+                let row = self.layout.rows()[row];
+                return &row[column..column + 1];
+            }
+        };
+
+        match (special, self.layout) {
+            (Special::Shift, KeyboardLayout::Lowercase) => "ABC",
+            (Special::Shift, _) => "abc",
+            (Special::Mode, KeyboardLayout::Digits) => "#+=",
+            (Special::Mode, _) => "123",
+            (Special::Space, _) => "Space",
+            (Special::Backspace, _) => "<",
+            (Special::Done, _) => "OK",
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        if self.selected != index {
+            self.selected = index;
+            self.dirty = true;
+        }
+    }
+
+    /// Move the selection in the grid, wrapping around the edges.
+    fn navigate(&mut self, rows: isize, keys: isize) {
+        let (row, column) = Self::position(self.selected);
+        if rows != 0 {
+            let row = (row as isize + rows).rem_euclid(CHAR_ROWS as isize + 1);
+            self.select(Self::index(row as usize, column));
+            return;
+        }
+
+        // Left and right stay in the row
+        let (first, count) = if row < CHAR_ROWS {
+            (row * COLUMNS, COLUMNS)
+        } else {
+            (COLUMNS * CHAR_ROWS, SPECIAL_KEYS.len())
+        };
+        let index = (self.selected - first) as isize + keys;
+        self.select(first + index.rem_euclid(count as isize) as usize);
+    }
+
+    /// Type the selected key.
+    fn press(&mut self) {
+        let special = match self.selected.checked_sub(COLUMNS * CHAR_ROWS) {
+            Some(special) => SPECIAL_KEYS[special].0,
+            None => {
+                let label = self.label(self.selected);
+                self.input = label.chars().next().map(KeyboardInput::Char);
+                return;
+            }
+        };
+
+        match special {
+            Special::Shift => self.set_layout(match self.layout {
+                KeyboardLayout::Lowercase => KeyboardLayout::Uppercase,
+                _ => KeyboardLayout::Lowercase,
+            }),
+            Special::Mode => self.set_layout(match self.layout {
+                KeyboardLayout::Digits => KeyboardLayout::Symbols,
+                _ => KeyboardLayout::Digits,
+            }),
+            Special::Space => self.input = Some(KeyboardInput::Char(' ')),
+            Special::Backspace => self.input = Some(KeyboardInput::Backspace),
+            Special::Done => self.input = Some(KeyboardInput::Done),
+        }
+    }
+}
+
+impl Drawable for Keyboard {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        Rect::new_filled(self.bbox, self.color.invert()).draw(canvas);
+
+        let highlight = self.focus != Focus::None || self.pressed;
+        for index in 0..KEYS {
+            let bbox = self.key_bbox(index);
+            let mut color = self.color;
+            if highlight && index == self.selected {
+                Rect::new_filled(bbox, self.color).draw(canvas);
+                color = self.color.invert();
+            } else if index >= COLUMNS * CHAR_ROWS {
+                // Outlines of neighbouring keys share the border
+                let mut outline = bbox;
+                if index > COLUMNS * CHAR_ROWS {
+                    outline.start.0 -= 1;
+                }
+                Rect::new(outline, self.color).draw(canvas);
+            }
+
+            Text::new((0, 0), self.label(index), color)
+                .align(bbox, Alignment::Center, Alignment::Center)
+                .draw(&mut Clip::new(canvas, bbox));
+        }
+    }
+}
+
+impl Widget for Keyboard {
+    fn min_size(&self) -> Size {
+        let cell = FONT_5X7.width as i32 + 2;
+
+        Size::new(
+            cell * COLUMNS as i32 - 1,
+            (FONT_5X7.height as i32 + 2) * (CHAR_ROWS as i32 + 1) - 1,
+        )
+    }
+
+    fn preferred_size(&self) -> Size {
+        Size::new(127, 47)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_editable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::KeyPress(Key::Up) | Event::KeyRepeat(Key::Up) => self.navigate(-1, 0),
+            Event::KeyPress(Key::Down) | Event::KeyRepeat(Key::Down) => self.navigate(1, 0),
+            Event::KeyPress(Key::Left) | Event::KeyRepeat(Key::Left) => self.navigate(0, -1),
+            Event::KeyPress(Key::Right) | Event::KeyRepeat(Key::Right) => self.navigate(0, 1),
+            Event::Encoder(steps) => {
+                let index = self.selected as isize + *steps as isize;
+                self.select(index.rem_euclid(KEYS as isize) as usize);
+            }
+            Event::KeyPress(Key::Enter | Key::EncoderPush) => self.press(),
+            Event::TouchDown(point) => {
+                let Some(index) = self.key_at(*point) else {
+                    return false;
+                };
+                self.select(index);
+                self.pressed = true;
+                self.dirty = true;
+            }
+            Event::TouchUp(point) => {
+                if !core::mem::take(&mut self.pressed) {
+                    return false;
+                }
+
+                self.dirty = true;
+                if self.key_at(*point) == Some(self.selected) {
+                    self.press();
+                }
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
@@ -0,0 +1,280 @@
+use crate::coordinates::bounding_box::BBox;
+use crate::coordinates::cvec::{Point, Size};
+use crate::focus::Focus;
+use crate::framebuffer::PixelDraw;
+use crate::input::Event;
+use crate::widgets::Widget;
+
+/// Widgets of one or several types, which containers and `FocusChain` address by index.
+///
+/// `Widget` is generic over the canvas, so there are no `dyn Widget` references.
+/// Instead, a list forwards each call to the widget with the index. It's implemented
+/// for arrays and slices of one widget type, and for tuples of up to six widgets of
+/// any types, so a screen can hold e.g. a label, a slider and a button together.
+/// Indexes out of range panic, like with slices.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::widgets::{Button, ProgressBar, WidgetList};
+/// let screen = (
+///     ProgressBar::new(BBox::new((0, 0), (127, 7)), 40, 100, Color::Black),
+///     Button::new(BBox::new((0, 50), (40, 63)), "Stop", Color::Black),
+/// );
+///
+/// assert_eq!(screen.len(), 2);
+/// assert!(screen.is_focusable(1));
+/// assert_eq!(screen.position_at((10, 55)), Some(1));
+/// ```
+pub trait WidgetList {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn min_size(&self, index: usize) -> Size;
+
+    fn preferred_size(&self, index: usize) -> Size;
+
+    fn bbox(&self, index: usize) -> BBox;
+
+    fn set_bbox(&mut self, index: usize, bbox: BBox);
+
+    fn is_focusable(&self, index: usize) -> bool;
+
+    fn is_editable(&self, index: usize) -> bool;
+
+    fn set_focus(&mut self, index: usize, focus: Focus);
+
+    fn handle_event(&mut self, index: usize, event: &Event) -> bool;
+
+    fn needs_redraw(&self, index: usize) -> bool;
+
+    fn mark_drawn(&mut self, index: usize);
+
+    fn draw_widget<C: PixelDraw>(&self, index: usize, canvas: &mut C);
+
+    fn redraw_widget<C: PixelDraw>(&mut self, index: usize, canvas: &mut C) -> bool;
+
+    /// Index of the first widget under the point.
+    fn position_at(&self, point: impl Into<Point>) -> Option<usize> {
+        let point = point.into();
+
+        (0..self.len()).find(|&index| self.bbox(index).contains(point))
+    }
+}
+
+impl<W: Widget> WidgetList for [W] {
+    fn len(&self) -> usize {
+        <[W]>::len(self)
+    }
+
+    fn min_size(&self, index: usize) -> Size {
+        self[index].min_size()
+    }
+
+    fn preferred_size(&self, index: usize) -> Size {
+        self[index].preferred_size()
+    }
+
+    fn bbox(&self, index: usize) -> BBox {
+        self[index].bbox()
+    }
+
+    fn set_bbox(&mut self, index: usize, bbox: BBox) {
+        self[index].set_bbox(bbox);
+    }
+
+    fn is_focusable(&self, index: usize) -> bool {
+        self[index].is_focusable()
+    }
+
+    fn is_editable(&self, index: usize) -> bool {
+        self[index].is_editable()
+    }
+
+    fn set_focus(&mut self, index: usize, focus: Focus) {
+        self[index].set_focus(focus);
+    }
+
+    fn handle_event(&mut self, index: usize, event: &Event) -> bool {
+        self[index].handle_event(event)
+    }
+
+    fn needs_redraw(&self, index: usize) -> bool {
+        self[index].needs_redraw()
+    }
+
+    fn mark_drawn(&mut self, index: usize) {
+        self[index].mark_drawn();
+    }
+
+    fn draw_widget<C: PixelDraw>(&self, index: usize, canvas: &mut C) {
+        self[index].draw(canvas);
+    }
+
+    fn redraw_widget<C: PixelDraw>(&mut self, index: usize, canvas: &mut C) -> bool {
+        self[index].redraw(canvas)
+    }
+}
+
+impl<W: Widget, const N: usize> WidgetList for [W; N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn min_size(&self, index: usize) -> Size {
+        self[..].min_size(index)
+    }
+
+    fn preferred_size(&self, index: usize) -> Size {
+        self[..].preferred_size(index)
+    }
+
+    fn bbox(&self, index: usize) -> BBox {
+        self[..].bbox(index)
+    }
+
+    fn set_bbox(&mut self, index: usize, bbox: BBox) {
+        self[..].set_bbox(index, bbox);
+    }
+
+    fn is_focusable(&self, index: usize) -> bool {
+        self[..].is_focusable(index)
+    }
+
+    fn is_editable(&self, index: usize) -> bool {
+        self[..].is_editable(index)
+    }
+
+    fn set_focus(&mut self, index: usize, focus: Focus) {
+        self[..].set_focus(index, focus);
+    }
+
+    fn handle_event(&mut self, index: usize, event: &Event) -> bool {
+        self[..].handle_event(index, event)
+    }
+
+    fn needs_redraw(&self, index: usize) -> bool {
+        self[..].needs_redraw(index)
+    }
+
+    fn mark_drawn(&mut self, index: usize) {
+        self[..].mark_drawn(index);
+    }
+
+    fn draw_widget<C: PixelDraw>(&self, index: usize, canvas: &mut C) {
+        self[..].draw_widget(index, canvas);
+    }
+
+    fn redraw_widget<C: PixelDraw>(&mut self, index: usize, canvas: &mut C) -> bool {
+        self[..].redraw_widget(index, canvas)
+    }
+}
+
+fn out_of_range(index: usize) -> ! {
+    panic!("no widget at index {}", index)
+}
+
+/// Forward to the tuple field with the index.
+macro_rules! impl_widget_list_tuple {
+    ($len:literal; $($name:ident $index:tt),+) => {
+        impl<$($name: Widget),+> WidgetList for ($($name,)+) {
+            fn len(&self) -> usize {
+                $len
+            }
+
+            fn min_size(&self, index: usize) -> Size {
+                match index {
+                    $($index => self.$index.min_size(),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn preferred_size(&self, index: usize) -> Size {
+                match index {
+                    $($index => self.$index.preferred_size(),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn bbox(&self, index: usize) -> BBox {
+                match index {
+                    $($index => self.$index.bbox(),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn set_bbox(&mut self, index: usize, bbox: BBox) {
+                match index {
+                    $($index => self.$index.set_bbox(bbox),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn is_focusable(&self, index: usize) -> bool {
+                match index {
+                    $($index => self.$index.is_focusable(),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn is_editable(&self, index: usize) -> bool {
+                match index {
+                    $($index => self.$index.is_editable(),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn set_focus(&mut self, index: usize, focus: Focus) {
+                match index {
+                    $($index => self.$index.set_focus(focus),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn handle_event(&mut self, index: usize, event: &Event) -> bool {
+                match index {
+                    $($index => self.$index.handle_event(event),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn needs_redraw(&self, index: usize) -> bool {
+                match index {
+                    $($index => self.$index.needs_redraw(),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn mark_drawn(&mut self, index: usize) {
+                match index {
+                    $($index => self.$index.mark_drawn(),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn draw_widget<C: PixelDraw>(&self, index: usize, canvas: &mut C) {
+                match index {
+                    $($index => self.$index.draw(canvas),)+
+                    _ => out_of_range(index),
+                }
+            }
+
+            fn redraw_widget<C: PixelDraw>(&mut self, index: usize, canvas: &mut C) -> bool {
+                match index {
+                    $($index => self.$index.redraw(canvas),)+
+                    _ => out_of_range(index),
+                }
+            }
+        }
+    };
+}
+
+impl_widget_list_tuple!(1; A 0);
+impl_widget_list_tuple!(2; A 0, B 1);
+impl_widget_list_tuple!(3; A 0, B 1, D 2);
+impl_widget_list_tuple!(4; A 0, B 1, D 2, E 3);
+impl_widget_list_tuple!(5; A 0, B 1, D 2, E 3, F 4);
+impl_widget_list_tuple!(6; A 0, B 1, D 2, E 3, F 4, G 5);
@@ -0,0 +1,326 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Point, Size};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::{Scroller, Widget};
+
+/// Height of a menu item, including padding.
+const ITEM_HEIGHT: i32 = FONT_5X7.height as i32 + 2;
+/// Width of the position indicator, including the gap before it.
+const SCROLLER_WIDTH: i32 = 4;
+/// Maximal nesting of hierarchical menus.
+pub const MAX_MENU_DEPTH: usize = 4;
+
+/// Entry of a hierarchical menu. Items without children can be activated.
+pub struct MenuItem<'a> {
+    pub label: &'a str,
+    pub children: &'a [MenuItem<'a>],
+}
+
+impl<'a> MenuItem<'a> {
+    pub const fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            children: &[],
+        }
+    }
+
+    pub const fn submenu(label: &'a str, children: &'a [MenuItem<'a>]) -> Self {
+        Self { label, children }
+    }
+}
+
+/// Source of menu labels. None of them needs an allocation.
+#[derive(Clone, Copy)]
+pub enum MenuItems<'a> {
+    Slice(&'a [&'a str]),
+    /// Number of items and a function which returns a label by its index.
+    Fn(usize, &'a dyn Fn(usize) -> &'a str),
+    Tree(&'a [MenuItem<'a>]),
+}
+
+/// Scrollable list of items with the selected one inverted.
+///
+/// `Up`, `Down` and the encoder move the selection, `Enter` activates the item or opens
+/// a submenu and `Back` returns to the parent menu. Usually the menu takes the whole
+/// screen and receives events directly rather than through a `FocusChain`.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::input::{Event, Key};
+/// # use rugui::widgets::{Menu, MenuItem, MenuItems, Widget};
+/// const NETWORK: [MenuItem<'static>; 2] = [MenuItem::new("Wi-Fi"), MenuItem::new("BLE")];
+/// const ROOT: [MenuItem<'static>; 2] =
+///     [MenuItem::submenu("Network", &NETWORK), MenuItem::new("About")];
+///
+/// let mut menu = Menu::new(BBox::new((0, 0), (127, 63)), MenuItems::Tree(&ROOT), Color::Black);
+/// menu.handle_event(&Event::KeyPress(Key::Enter));
+/// menu.handle_event(&Event::KeyPress(Key::Down));
+/// menu.handle_event(&Event::KeyPress(Key::Enter));
+/// assert_eq!(menu.take_activated(), Some(1));
+/// assert_eq!(menu.path(), &[0, 1]);
+/// ```
+pub struct Menu<'a> {
+    bbox: BBox,
+    root: MenuItems<'a>,
+    /// Selected item on every level, the last one is the current level.
+    path: [usize; MAX_MENU_DEPTH],
+    depth: usize,
+    first: usize,
+    activated: bool,
+    color: Color,
+    focus: Focus,
+    dirty: bool,
+}
+
+impl<'a> Menu<'a> {
+    pub fn new(bbox: BBox, items: MenuItems<'a>, color: Color) -> Self {
+        Self {
+            bbox,
+            root: items,
+            path: [0; MAX_MENU_DEPTH],
+            depth: 1,
+            first: 0,
+            activated: false,
+            color,
+            focus: Focus::None,
+            dirty: true,
+        }
+    }
+
+    /// Items of the currently shown level.
+    fn items(&self) -> MenuItems<'a> {
+        let mut items = self.root;
+        for &index in &self.path[..self.depth - 1] {
+            if let MenuItems::Tree(tree) = items {
+                items = MenuItems::Tree(tree[index].children);
+            }
+        }
+
+        items
+    }
+
+    pub fn len(&self) -> usize {
+        match self.items() {
+            MenuItems::Slice(items) => items.len(),
+            MenuItems::Fn(len, _) => len,
+            MenuItems::Tree(items) => items.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn label(&self, index: usize) -> &'a str {
+        match self.items() {
+            MenuItems::Slice(items) => items[index],
+            MenuItems::Fn(_, label) => label(index),
+            MenuItems::Tree(items) => items[index].label,
+        }
+    }
+
+    /// Index of the selected item on the current level.
+    pub fn selected(&self) -> usize {
+        self.path[self.depth - 1]
+    }
+
+    /// Indexes of selected items from the root menu to the current level.
+    pub fn path(&self) -> &[usize] {
+        &self.path[..self.depth]
+    }
+
+    /// Select the item and scroll to keep it visible.
+    pub fn select(&mut self, index: usize) {
+        let index = index.min(self.len().saturating_sub(1));
+        let visible = self.visible_items().max(1);
+
+        if index < self.first {
+            self.first = index;
+        } else if index >= self.first + visible {
+            self.first = index + 1 - visible;
+        }
+
+        self.path[self.depth - 1] = index;
+        self.dirty = true;
+    }
+
+    /// Returns the index of the item which was activated with `Enter` since the last call.
+    pub fn take_activated(&mut self) -> Option<usize> {
+        if core::mem::take(&mut self.activated) {
+            Some(self.selected())
+        } else {
+            None
+        }
+    }
+
+    /// Number of items which fit into the menu.
+    pub fn visible_items(&self) -> usize {
+        ((self.bbox.height() as i32 + 1) / ITEM_HEIGHT).max(0) as usize
+    }
+
+    /// Open the submenu of the selected item, or activate it if it has no children.
+    fn enter(&mut self) {
+        if let MenuItems::Tree(items) = self.items() {
+            let has_children = items
+                .get(self.selected())
+                .is_some_and(|item| !item.children.is_empty());
+
+            if has_children && self.depth < MAX_MENU_DEPTH {
+                self.depth += 1;
+                self.path[self.depth - 1] = 0;
+                self.first = 0;
+                self.dirty = true;
+                return;
+            }
+        }
+
+        if !self.is_empty() {
+            self.activated = true;
+        }
+    }
+
+    /// Return to the parent menu. Returns `false` on the root level.
+    fn back(&mut self) -> bool {
+        if self.depth == 1 {
+            return false;
+        }
+
+        self.depth -= 1;
+        self.first = 0;
+        self.select(self.selected());
+
+        true
+    }
+}
+
+impl<'a> Drawable for Menu<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+
+        let len = self.len();
+        let visible = self.visible_items();
+        let mut list = bbox;
+
+        if len > visible {
+            let (items, scroller) = bbox.split(Axis::X, bbox.end.0 - SCROLLER_WIDTH + 2);
+            list = BBox::new(items.start, (items.end.0 - 1, items.end.1));
+            Scroller::new(
+                scroller,
+                self.first as u32,
+                visible as u32,
+                len as u32,
+                Orientation::Vertical,
+                self.color,
+            )
+            .draw(canvas);
+        }
+
+        let mut y = bbox.start.1;
+        for index in (self.first..len).take(visible) {
+            let item = BBox::new((list.start.0, y), (list.end.0, y + ITEM_HEIGHT - 1));
+            let mut color = self.color;
+
+            if index == self.selected() {
+                Rect::new_filled(item, self.color).draw(canvas);
+                color = self.color.invert();
+            }
+
+            let mut clip = Clip::new(canvas, item);
+            Text::new(
+                (item.start.0 + 2, item.start.1 + 1),
+                self.label(index),
+                color,
+            )
+            .draw(&mut clip);
+
+            y += ITEM_HEIGHT;
+        }
+
+        if self.focus != Focus::None {
+            Rect::new(bbox, self.color).draw(canvas);
+        }
+    }
+}
+
+impl<'a> Widget for Menu<'a> {
+    fn min_size(&self) -> Size {
+        Size::new(FONT_5X7.advance() as i32 * 4, ITEM_HEIGHT - 1)
+    }
+
+    /// Fits all items of the current level.
+    fn preferred_size(&self) -> Size {
+        let widest = (0..self.len())
+            .map(|index| FONT_5X7.text_size(self.label(index)).0)
+            .max()
+            .unwrap_or(0);
+
+        Size::new(widest + 4, ITEM_HEIGHT * self.len().max(1) as i32 - 1)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.select(self.selected());
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let step = match event {
+            Event::KeyPress(Key::Down) | Event::KeyRepeat(Key::Down) => 1,
+            Event::KeyPress(Key::Up) | Event::KeyRepeat(Key::Up) => -1,
+            Event::Encoder(steps) => *steps as isize,
+            Event::KeyPress(Key::Enter | Key::EncoderPush | Key::Right) => {
+                self.enter();
+                return true;
+            }
+            Event::KeyPress(Key::Back | Key::Left) => return self.back(),
+            // Touch selects an item and releasing the finger on it activates the item
+            Event::TouchDown(Point { y, .. }) | Event::TouchUp(Point { y, .. }) => {
+                let index = self.first + ((y - self.bbox.start.1) / ITEM_HEIGHT).max(0) as usize;
+                if index >= self.len() {
+                    return false;
+                }
+
+                if let Event::TouchUp(_) = event {
+                    if index == self.selected() {
+                        self.enter();
+                    }
+                } else {
+                    self.select(index);
+                }
+                return true;
+            }
+            _ => return false,
+        };
+
+        let last = self.len().saturating_sub(1) as isize;
+        self.select((self.selected() as isize + step).clamp(0, last) as usize);
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
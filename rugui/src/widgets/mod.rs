@@ -0,0 +1,99 @@
+use crate::coordinates::bounding_box::BBox;
+use crate::coordinates::cvec::Size;
+use crate::focus::Focus;
+use crate::framebuffer::PixelDraw;
+use crate::geometry::Drawable;
+use crate::input::Event;
+
+mod button;
+mod chart;
+mod checkbox;
+mod gauge;
+mod keyboard;
+mod list;
+mod menu;
+mod overlay;
+mod pager;
+mod progress_bar;
+mod radio_group;
+mod scroller;
+mod slider;
+mod spin_box;
+mod status_bar;
+mod tab_view;
+mod table;
+mod text_input;
+
+pub use button::*;
+pub use chart::*;
+pub use checkbox::*;
+pub use gauge::*;
+pub use keyboard::*;
+pub use list::*;
+pub use menu::*;
+pub use overlay::*;
+pub use pager::*;
+pub use progress_bar::*;
+pub use radio_group::*;
+pub use scroller::*;
+pub use slider::*;
+pub use spin_box::*;
+pub use status_bar::*;
+pub use tab_view::*;
+pub use table::*;
+pub use text_input::*;
+
+/// Interactive element of the interface, which can be placed by a layout.
+///
+/// Sizes use the same convention as `BBox::width` and `BBox::height`, so a widget
+/// fits into `BBox::from_relative(start, size)`.
+pub trait Widget: Drawable {
+    /// Smallest size the widget can be drawn in.
+    fn min_size(&self) -> Size;
+
+    /// Size the widget looks best in.
+    fn preferred_size(&self) -> Size {
+        self.min_size()
+    }
+
+    fn bbox(&self) -> BBox;
+
+    /// Place the widget. Marks it for redraw.
+    fn set_bbox(&mut self, bbox: BBox);
+
+    /// Can the widget receive focus by navigation?
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
+    /// Should the widget capture input when `Enter` is pressed on it?
+    fn is_editable(&self) -> bool {
+        false
+    }
+
+    /// Called by `FocusChain` when the focus state changes.
+    fn set_focus(&mut self, _focus: Focus) {}
+
+    /// Returns `true` if the event was consumed. While the input is captured, consuming
+    /// `Enter` or `EncoderPush` keeps it captured.
+    fn handle_event(&mut self, _event: &Event) -> bool {
+        false
+    }
+
+    /// Did the state change since the last `mark_drawn`?
+    fn needs_redraw(&self) -> bool;
+
+    fn mark_drawn(&mut self);
+
+    /// Draw the widget only if its state has changed. Returns `true` if it was drawn.
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        if !self.needs_redraw() {
+            return false;
+        }
+
+        self.draw(canvas);
+        self.mark_drawn();
+
+        true
+    }
+}
@@ -0,0 +1,513 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::Size;
+use crate::focus::FocusChain;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::{Button, Widget};
+
+/// Offset of the shadow cast by overlays to the bottom right.
+const SHADOW: i32 = 2;
+/// Space between the frame and the content.
+const PADDING: i32 = 3;
+/// Gap between dialog buttons.
+const BUTTON_GAP: i32 = 4;
+/// Distance of a toast from the bottom of the screen.
+const TOAST_MARGIN: i32 = 4;
+
+/// Area covered by an overlay, including its shadow.
+fn covered(bbox: BBox) -> BBox {
+    BBox::new(bbox.start, (bbox.end.0 + SHADOW, bbox.end.1 + SHADOW))
+}
+
+/// Clear the box, outline it and cast a shadow, so it stands out over whatever is under it.
+fn draw_frame<C: PixelDraw>(canvas: &mut C, bbox: BBox, color: Color) {
+    let shadow = BBox::new(
+        (bbox.start.0 + SHADOW, bbox.start.1 + SHADOW),
+        (bbox.end.0 + SHADOW, bbox.end.1 + SHADOW),
+    );
+
+    Rect::new_filled(shadow, color).draw(canvas);
+    Rect::new_filled(bbox, color.invert()).draw(canvas);
+    Rect::new(bbox, color).draw(canvas);
+}
+
+/// Modal window with a title, a wrapped message and a row of buttons.
+///
+/// While a dialog is shown, pass all events to its `handle_event` instead of the
+/// `FocusChain` of the screen. It consumes every event, so nothing reaches the widgets
+/// under it. Navigation keys and the encoder move the focus between its buttons.
+///
+/// The dialog doesn't remember what it covers. Save its `area` with a `Backup` before
+/// it's drawn and restore it once a button is clicked, or redraw the widgets under it.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::{backup_size, Backup, Color, Framebuffer};
+/// # use rugui::input::{Event, Key};
+/// # use rugui::widgets::{Dialog, Widget};
+/// # let mut buffer = [0; 128 * 8];
+/// # let mut framebuffer = Framebuffer::new(128, 64, &mut buffer).unwrap();
+/// let mut dialog = Dialog::new(
+///     BBox::new((14, 8), (111, 51)),
+///     "Update",
+///     "Install the new firmware?",
+///     ["Now", "Later"],
+///     Color::Black,
+/// )
+/// .cancel(1);
+///
+/// let mut backup = Backup::<{ backup_size(100, 46) }>::new();
+/// backup.save(&framebuffer, dialog.area());
+/// dialog.redraw(&mut framebuffer);
+///
+/// dialog.handle_event(&Event::KeyPress(Key::Back));
+/// if dialog.take_result() == Some(1) {
+///     backup.restore(&mut framebuffer);
+/// }
+/// ```
+pub struct Dialog<'a, const N: usize> {
+    bbox: BBox,
+    title: &'a str,
+    text: &'a str,
+    buttons: [Button<'a>; N],
+    focus: FocusChain,
+    cancel: Option<usize>,
+    result: Option<usize>,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a, const N: usize> Dialog<'a, N> {
+    /// The dialog has no title bar if the `title` is empty. The first button is focused.
+    pub fn new(
+        bbox: BBox,
+        title: &'a str,
+        text: &'a str,
+        labels: [&'a str; N],
+        color: Color,
+    ) -> Self {
+        let zero = BBox::new((0, 0), (0, 0));
+        let mut dialog = Self {
+            bbox,
+            title,
+            text,
+            buttons: labels.map(|label| Button::new(zero, label, color)),
+            focus: FocusChain::new(),
+            cancel: None,
+            result: None,
+            color,
+            dirty: true,
+        };
+        dialog.layout();
+        dialog.focus.focus(&mut dialog.buttons, Some(0));
+
+        dialog
+    }
+
+    /// Button which is clicked by `Back`. Without it `Back` does nothing.
+    pub fn cancel(mut self, index: usize) -> Self {
+        self.cancel = Some(index).filter(|&index| index < N);
+
+        self
+    }
+
+    /// Button which is focused when the dialog opens.
+    pub fn focused(mut self, index: usize) -> Self {
+        self.focus.focus(&mut self.buttons, Some(index));
+
+        self
+    }
+
+    pub fn focused_button(&self) -> Option<usize> {
+        self.focus.current()
+    }
+
+    /// Index of the button clicked since the last call.
+    pub fn take_result(&mut self) -> Option<usize> {
+        self.result.take()
+    }
+
+    /// Area covered by the dialog, including its shadow.
+    pub fn area(&self) -> BBox {
+        covered(self.bbox)
+    }
+
+    fn title_bar(&self) -> Option<BBox> {
+        if self.title.is_empty() {
+            return None;
+        }
+
+        Some(BBox::new(
+            self.bbox.start,
+            (
+                self.bbox.end.0,
+                self.bbox.start.1 + FONT_5X7.height as i32 + 1,
+            ),
+        ))
+    }
+
+    /// Part between the title and the buttons.
+    fn body(&self) -> BBox {
+        let top = self.title_bar().map_or(self.bbox.start.1, |bar| bar.end.1);
+        let bottom = self
+            .buttons
+            .first()
+            .map_or(self.bbox.end.1, |button| button.bbox().start.1);
+
+        BBox::new(
+            (self.bbox.start.0 + PADDING, top + PADDING),
+            (self.bbox.end.0 - PADDING, bottom - PADDING),
+        )
+    }
+
+    /// Buttons of the same width are centered at the bottom.
+    fn layout(&mut self) {
+        let height = self.buttons.iter().map(|b| b.preferred_size().height);
+        let height = height.max().unwrap_or(0);
+        let widest = self.buttons.iter().map(|b| b.preferred_size().width + 1);
+        let widest = widest.max().unwrap_or(0);
+
+        let count = N as i32;
+        let available = self.bbox.end.0 - self.bbox.start.0 + 1 - PADDING * 2;
+        let gaps = BUTTON_GAP * (count - 1).max(0);
+        let width = widest.min((available - gaps) / count.max(1));
+
+        let bottom = self.bbox.end.1 - PADDING;
+        let mut x = self.bbox.start.0 + PADDING + (available - width * count - gaps) / 2;
+        for button in &mut self.buttons {
+            button.set_bbox(BBox::new((x, bottom - height), (x + width - 1, bottom)));
+            x += width + BUTTON_GAP;
+        }
+
+        self.dirty = true;
+    }
+}
+
+impl<'a, const N: usize> Drawable for Dialog<'a, N> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        draw_frame(canvas, self.bbox, self.color);
+
+        if let Some(bar) = self.title_bar() {
+            Rect::new_filled(bar, self.color).draw(canvas);
+            Text::new((0, 0), self.title, self.color.invert())
+                .align(bar, Alignment::Center, Alignment::Center)
+                .draw(&mut Clip::new(canvas, bar));
+        }
+
+        let body = self.body();
+        let width = body.end.0 - body.start.0 + 1;
+        let mut clip = Clip::new(canvas, body);
+        for (index, line) in FONT_5X7.wrap(self.text, width).enumerate() {
+            let top = body.start.1 + index as i32 * (FONT_5X7.height as i32 + 1);
+            let row = BBox::new((body.start.0, top), (body.end.0, top));
+            Text::new((0, 0), line, self.color)
+                .align(row, Alignment::Center, Alignment::Start)
+                .draw(&mut clip);
+        }
+
+        self.buttons.draw(canvas);
+    }
+}
+
+impl<'a, const N: usize> Widget for Dialog<'a, N> {
+    fn min_size(&self) -> Size {
+        let widths = self.buttons.iter().map(|b| b.min_size().width + 1);
+        let buttons = widths.sum::<i32>() + BUTTON_GAP * (N as i32 - 1).max(0);
+        let title = FONT_5X7.text_size(self.title).0;
+        let button_height = self.buttons.iter().map(|b| b.min_size().height + 1);
+        let title_height = match self.title_bar() {
+            Some(bar) => bar.height() as i32 + 1,
+            None => 1,
+        };
+        let height = title_height + FONT_5X7.height as i32 + button_height.max().unwrap_or(0);
+
+        Size::new(
+            buttons.max(title) + PADDING * 2 - 1,
+            height + PADDING * 3 - 1,
+        )
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.layout();
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    /// Consumes every event, the dialog is modal.
+    fn handle_event(&mut self, event: &Event) -> bool {
+        if let Event::KeyPress(Key::Back) = event {
+            if self.cancel.is_some() {
+                self.result = self.cancel;
+            }
+            return true;
+        }
+
+        self.focus.route(&mut self.buttons, event);
+        for (index, button) in self.buttons.iter_mut().enumerate() {
+            if button.take_clicked() {
+                self.result = Some(index);
+            }
+        }
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty || self.buttons.iter().any(|button| button.needs_redraw())
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+        for button in &mut self.buttons {
+            button.mark_drawn();
+        }
+    }
+
+    /// Only the changed buttons are drawn, unless the whole dialog has to be.
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        if self.dirty {
+            self.draw(canvas);
+            self.mark_drawn();
+            return true;
+        }
+
+        let mut drawn = false;
+        for button in &mut self.buttons {
+            drawn |= button.redraw(canvas);
+        }
+
+        drawn
+    }
+}
+
+/// Dialog asking to confirm or to cancel an action. `Back` cancels it.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::input::{Event, Key};
+/// # use rugui::widgets::{MessageBox, Widget};
+/// let mut message = MessageBox::new(BBox::new((0, 0), (127, 63)), "", "Delete?", Color::Black)
+///     .labels("Yes", "No");
+///
+/// message.handle_event(&Event::KeyPress(Key::Enter));
+/// message.handle_event(&Event::KeyRelease(Key::Enter));
+/// assert_eq!(message.take_answer(), Some(true));
+/// ```
+pub struct MessageBox<'a> {
+    dialog: Dialog<'a, 2>,
+}
+
+impl<'a> MessageBox<'a> {
+    pub fn new(bbox: BBox, title: &'a str, text: &'a str, color: Color) -> Self {
+        Self {
+            dialog: Dialog::new(bbox, title, text, ["OK", "Cancel"], color).cancel(1),
+        }
+    }
+
+    /// Labels of the confirm and the cancel buttons, `OK` and `Cancel` by default.
+    pub fn labels(self, confirm: &'a str, cancel: &'a str) -> Self {
+        let Dialog {
+            bbox,
+            title,
+            text,
+            color,
+            ..
+        } = self.dialog;
+
+        Self {
+            dialog: Dialog::new(bbox, title, text, [confirm, cancel], color).cancel(1),
+        }
+    }
+
+    /// `Some(true)` if the action was confirmed since the last call, `Some(false)`
+    /// if it was cancelled.
+    pub fn take_answer(&mut self) -> Option<bool> {
+        self.dialog.take_result().map(|index| index == 0)
+    }
+
+    /// Area covered by the message box, including its shadow.
+    pub fn area(&self) -> BBox {
+        self.dialog.area()
+    }
+}
+
+impl<'a> Drawable for MessageBox<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        self.dialog.draw(canvas);
+    }
+}
+
+impl<'a> Widget for MessageBox<'a> {
+    fn min_size(&self) -> Size {
+        self.dialog.min_size()
+    }
+
+    fn bbox(&self) -> BBox {
+        self.dialog.bbox()
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.dialog.set_bbox(bbox);
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.dialog.handle_event(event)
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dialog.needs_redraw()
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dialog.mark_drawn();
+    }
+
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        self.dialog.redraw(canvas)
+    }
+}
+
+/// Short notification at the bottom of the screen, which disappears by itself.
+///
+/// A hidden toast draws nothing. Once `update` reports that it expired, restore the
+/// `area` under it from a `Backup` or redraw the widgets there.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::widgets::Toast;
+/// let mut toast = Toast::new(BBox::new((0, 0), (127, 63)), Color::Black).duration(1000);
+///
+/// toast.show("Saved", 5000);
+/// assert!(!toast.update(5999));
+/// assert!(toast.update(6000));
+/// assert!(!toast.is_visible());
+/// ```
+pub struct Toast<'a> {
+    /// Area the toast is placed in
+    screen: BBox,
+    text: &'a str,
+    duration: u32,
+    shown_at: u32,
+    visible: bool,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> Toast<'a> {
+    /// The toast is hidden until `show`. It's shown for 2 seconds by default.
+    pub fn new(screen: BBox, color: Color) -> Self {
+        Self {
+            screen,
+            text: "",
+            duration: 2000,
+            shown_at: 0,
+            visible: false,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// How long the toast is shown, in milliseconds.
+    pub fn duration(mut self, duration: u32) -> Self {
+        self.duration = duration;
+
+        self
+    }
+
+    /// Show the text with the current time in milliseconds. A shown toast is replaced,
+    /// its `area` may change with the text.
+    pub fn show(&mut self, text: &'a str, now: u32) {
+        self.text = text;
+        self.shown_at = now;
+        self.visible = true;
+        self.dirty = true;
+    }
+
+    pub fn hide(&mut self) {
+        if self.visible {
+            self.visible = false;
+            self.dirty = true;
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Feed the current time in milliseconds. Returns `true` when the toast expires.
+    pub fn update(&mut self, now: u32) -> bool {
+        if self.visible && now.wrapping_sub(self.shown_at) >= self.duration {
+            self.hide();
+            return true;
+        }
+
+        false
+    }
+
+    /// Area covered by the toast, including its shadow.
+    pub fn area(&self) -> BBox {
+        covered(self.bbox())
+    }
+}
+
+impl<'a> Drawable for Toast<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        if !self.visible {
+            return;
+        }
+
+        let bbox = self.bbox();
+        draw_frame(canvas, bbox, self.color);
+        Text::new((0, 0), self.text, self.color)
+            .align(bbox, Alignment::Center, Alignment::Center)
+            .draw(&mut Clip::new(canvas, bbox));
+    }
+}
+
+impl<'a> Widget for Toast<'a> {
+    fn min_size(&self) -> Size {
+        let (width, height) = FONT_5X7.text_size(self.text);
+
+        Size::new(width + PADDING * 2 - 1, height + PADDING * 2 - 1)
+    }
+
+    /// Centered at the bottom of the screen, narrowed to fit it.
+    fn bbox(&self) -> BBox {
+        let screen = self.screen;
+        let Size { width, height } = self.min_size();
+        let width = width.min(screen.width() as i32 - SHADOW);
+        let x = screen.start.0 + (screen.width() as i32 - SHADOW - width) / 2;
+        let bottom = screen.end.1 - TOAST_MARGIN - SHADOW;
+
+        BBox::new((x, bottom - height), (x + width, bottom))
+    }
+
+    /// Place the toast within the screen area.
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.screen = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
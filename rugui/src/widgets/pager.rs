@@ -0,0 +1,352 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Point, Size, Vec2};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Circle, Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::widgets::{Widget, WidgetList};
+
+/// Radius of a page indicator dot.
+const DOT_RADIUS: i32 = 2;
+/// Distance between centers of neighbouring dots.
+const DOT_SPACING: i32 = 8;
+/// Width of the indicator strip across the paging direction.
+const INDICATOR_WIDTH: i32 = DOT_RADIUS * 2 + 3;
+/// Shortest swipe which turns the page.
+const SWIPE_DISTANCE: i32 = 16;
+
+/// Pages shown one at a time, with dots indicating the current one.
+///
+/// `Orientation::Horizontal` turns the pages by swiping left and right and shows the dots
+/// below the pages, `Vertical` swipes up and down with the dots on the right. A tap on
+/// the dots turns the page towards it. While the pager captures input, arrows and the
+/// encoder turn the pages. Buttons can call `next` and `prev`. Pages are a `WidgetList`,
+/// so they can be different widgets.
+///
+/// A touch reaches the page once it's released without swiping, so that a swipe which
+/// starts on a button doesn't press it.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::coordinates::cvec::Point;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::input::Event;
+/// # use rugui::widgets::{Gauge, Pager, ProgressBar, Widget};
+/// let zero = BBox::new((0, 0), (0, 0));
+/// let pages = (
+///     Gauge::new(zero, 0, 100, 10, Color::Black),
+///     ProgressBar::new(zero, 90, 100, Color::Black),
+/// );
+/// let mut pager = Pager::new(BBox::new((0, 0), (127, 63)), pages, Color::Black);
+///
+/// pager.handle_event(&Event::TouchDown(Point::new(100, 20)));
+/// pager.handle_event(&Event::TouchUp(Point::new(40, 22)));
+/// assert_eq!(pager.take_changed(), Some(1));
+/// ```
+pub struct Pager<L: WidgetList> {
+    bbox: BBox,
+    pages: L,
+    current: usize,
+    orient: Orientation,
+    /// Start of a touch on the page, which may become a swipe
+    touch: Option<Point>,
+    changed: bool,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+impl<L: WidgetList> Pager<L> {
+    /// Pages are placed above the dots, the first one is shown.
+    pub fn new(bbox: BBox, pages: L, color: Color) -> Self {
+        let mut pager = Self {
+            bbox,
+            pages,
+            current: 0,
+            orient: Orientation::Horizontal,
+            touch: None,
+            changed: false,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        };
+        pager.layout();
+
+        pager
+    }
+
+    /// Direction of paging.
+    pub fn orientation(mut self, orient: Orientation) -> Self {
+        self.orient = orient;
+        self.layout();
+
+        self
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Show the page without reporting a change.
+    pub fn set_current(&mut self, index: usize) {
+        if index < self.pages.len() && self.current != index {
+            self.current = index;
+            self.dirty = true;
+        }
+    }
+
+    /// Turn to the next page, the last one stays.
+    pub fn next(&mut self) {
+        self.turn((self.current + 1).min(self.pages.len().saturating_sub(1)));
+    }
+
+    /// Turn to the previous page, the first one stays.
+    pub fn prev(&mut self) {
+        self.turn(self.current.saturating_sub(1));
+    }
+
+    /// Returns the index of the current page if the user turned it since the last call.
+    pub fn take_changed(&mut self) -> Option<usize> {
+        if core::mem::take(&mut self.changed) {
+            Some(self.current)
+        } else {
+            None
+        }
+    }
+
+    pub fn pages(&self) -> &L {
+        &self.pages
+    }
+
+    pub fn pages_mut(&mut self) -> &mut L {
+        &mut self.pages
+    }
+
+    fn turn(&mut self, index: usize) {
+        if self.current != index {
+            self.set_current(index);
+            self.changed = true;
+        }
+    }
+
+    /// Strip with the dots along the bottom or the right edge.
+    fn indicator(&self) -> BBox {
+        let bbox = self.bbox;
+        match self.orient {
+            Orientation::Horizontal => {
+                BBox::new((bbox.start.0, bbox.end.1 - INDICATOR_WIDTH + 1), bbox.end)
+            }
+            Orientation::Vertical => {
+                BBox::new((bbox.end.0 - INDICATOR_WIDTH + 1, bbox.start.1), bbox.end)
+            }
+        }
+    }
+
+    /// Area the pages are placed in.
+    fn content(&self) -> BBox {
+        let indicator = self.indicator();
+        match self.orient {
+            Orientation::Horizontal => {
+                BBox::new(self.bbox.start, (self.bbox.end.0, indicator.start.1 - 1))
+            }
+            Orientation::Vertical => {
+                BBox::new(self.bbox.start, (indicator.start.0 - 1, self.bbox.end.1))
+            }
+        }
+    }
+
+    /// Center of the dot of the page with the `index`.
+    fn dot(&self, index: usize) -> Vec2 {
+        let indicator = self.indicator();
+        let count = self.pages.len() as i32;
+        let offset = DOT_SPACING * index as i32 - DOT_SPACING * (count - 1) / 2;
+        let center = (
+            indicator.start.0 + indicator.width() as i32 / 2,
+            indicator.start.1 + indicator.height() as i32 / 2,
+        );
+
+        match self.orient {
+            Orientation::Horizontal => (center.0 + offset, center.1),
+            Orientation::Vertical => (center.0, center.1 + offset),
+        }
+    }
+
+    /// Position along the paging direction.
+    fn along(&self, Point { x, y }: Point) -> i32 {
+        match self.orient {
+            Orientation::Horizontal => x,
+            Orientation::Vertical => y,
+        }
+    }
+
+    fn layout(&mut self) {
+        let content = self.content();
+        for index in 0..self.pages.len() {
+            self.pages.set_bbox(index, content);
+        }
+
+        self.dirty = true;
+    }
+}
+
+impl<L: WidgetList> Drawable for Pager<L> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let indicator = self.indicator();
+        Rect::new_filled(indicator, self.color.invert()).draw(canvas);
+
+        for index in 0..self.pages.len() {
+            Circle::new(self.dot(index), DOT_RADIUS as u32, self.color)
+                .filled(index == self.current)
+                .draw(canvas);
+        }
+
+        // Editing is shown with a double outline
+        if self.focus != Focus::None {
+            Rect::new(indicator, self.color).draw(canvas);
+        }
+        if self.focus == Focus::Editing {
+            let inner = BBox::new(
+                (indicator.start.0 + 1, indicator.start.1 + 1),
+                (indicator.end.0 - 1, indicator.end.1 - 1),
+            );
+            Rect::new(inner, self.color).draw(canvas);
+        }
+
+        if self.current < self.pages.len() {
+            let mut clip = Clip::new(canvas, self.content());
+            self.pages.draw_widget(self.current, &mut clip);
+        }
+    }
+}
+
+impl<L: WidgetList> Widget for Pager<L> {
+    fn min_size(&self) -> Size {
+        let pages = 0..self.pages.len();
+        let width = pages.clone().map(|i| self.pages.min_size(i).width).max();
+        let height = pages.map(|i| self.pages.min_size(i).height).max();
+        let (width, height) = (width.unwrap_or(0), height.unwrap_or(0));
+        let dots = DOT_SPACING * self.pages.len() as i32;
+
+        match self.orient {
+            Orientation::Horizontal => Size::new(width.max(dots), height + INDICATOR_WIDTH),
+            Orientation::Vertical => Size::new(width + INDICATOR_WIDTH, height.max(dots)),
+        }
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.layout();
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_editable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        if let Some(point) = event.position() {
+            let indicator = self.indicator();
+            let Point { x, y } = point;
+            let on_indicator = x >= indicator.start.0 && y >= indicator.start.1;
+            if on_indicator && self.touch.is_none() {
+                if let Event::TouchDown(_) = event {
+                    let current = self.along(self.dot(self.current).into());
+                    match self.along(point).cmp(&current) {
+                        core::cmp::Ordering::Less => self.prev(),
+                        core::cmp::Ordering::Greater => self.next(),
+                        core::cmp::Ordering::Equal => {}
+                    }
+                }
+                return true;
+            }
+
+            match event {
+                Event::TouchDown(_) => {
+                    self.touch = Some(point);
+                    return true;
+                }
+                Event::TouchMove(_) if self.touch.is_some() => return true,
+                Event::TouchUp(_) => {
+                    if let Some(start) = self.touch.take() {
+                        let distance = self.along(point) - self.along(start);
+                        if distance <= -SWIPE_DISTANCE {
+                            self.next();
+                            return true;
+                        }
+                        if distance >= SWIPE_DISTANCE {
+                            self.prev();
+                            return true;
+                        }
+
+                        // Not a swipe, the page gets the touch which was held back
+                        if self.current < self.pages.len() {
+                            self.pages
+                                .handle_event(self.current, &Event::TouchDown(start));
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            return self.current < self.pages.len() && self.pages.handle_event(self.current, event);
+        }
+
+        match event {
+            Event::KeyPress(Key::Left | Key::Up) | Event::KeyRepeat(Key::Left | Key::Up) => {
+                self.prev();
+            }
+            Event::KeyPress(Key::Right | Key::Down) | Event::KeyRepeat(Key::Right | Key::Down) => {
+                self.next();
+            }
+            Event::Encoder(steps) => {
+                let index = self.current as isize + *steps as isize;
+                self.turn(index.min(self.pages.len() as isize - 1).max(0) as usize);
+            }
+            _ => {
+                return self.current < self.pages.len()
+                    && self.pages.handle_event(self.current, event);
+            }
+        }
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty || (self.current < self.pages.len() && self.pages.needs_redraw(self.current))
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+        if self.current < self.pages.len() {
+            self.pages.mark_drawn(self.current);
+        }
+    }
+
+    /// Only the current page is drawn, unless it was turned.
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        if self.dirty {
+            self.draw(canvas);
+            self.mark_drawn();
+            return true;
+        }
+
+        if self.current >= self.pages.len() {
+            return false;
+        }
+
+        let mut clip = Clip::new(canvas, self.content());
+        self.pages.redraw_widget(self.current, &mut clip)
+    }
+}
@@ -0,0 +1,305 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::Size;
+use crate::fixed;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Rect};
+use crate::text::{format_number, Text, FONT_5X7, MAX_NUMBER};
+use crate::widgets::Widget;
+
+/// Gap between blocks of a segmented bar.
+const SEGMENT_GAP: i32 = 1;
+
+/// Shows the progress of an operation as a filled part of an outlined bar.
+///
+/// The bar is horizontal and continuous by default. It can be vertical, filled from
+/// the bottom, split into blocks, or show a block running back and forth when
+/// the progress is unknown. The percentage label is inverted over the filled part,
+/// as if drawn with XOR.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::widgets::ProgressBar;
+/// let bar = ProgressBar::new(BBox::new((0, 0), (63, 10)), 3, 4, Color::Black)
+///     .segments(8)
+///     .label(true);
+/// assert_eq!(bar.percent(), 75);
+/// ```
+pub struct ProgressBar {
+    bbox: BBox,
+    value: u32,
+    max: u32,
+    orient: Orientation,
+    segments: u32,
+    label: bool,
+    indeterminate: bool,
+    /// Position of the running block in the indeterminate mode
+    phase: u32,
+    color: Color,
+    dirty: bool,
+}
+
+impl ProgressBar {
+    /// `value` is clamped to `max`. Zero `max` is shown as a complete operation.
+    pub fn new(bbox: BBox, value: u32, max: u32, color: Color) -> Self {
+        Self {
+            bbox,
+            value: value.min(max),
+            max,
+            orient: Orientation::Horizontal,
+            segments: 0,
+            label: false,
+            indeterminate: false,
+            phase: 0,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Vertical bar is filled from the bottom.
+    pub fn orientation(mut self, orient: Orientation) -> Self {
+        self.orient = orient;
+
+        self
+    }
+
+    /// Split the bar into blocks which are filled one by one. Zero makes it continuous.
+    pub fn segments(mut self, segments: u32) -> Self {
+        self.segments = segments;
+
+        self
+    }
+
+    /// Show the percentage in the middle of the bar.
+    pub fn label(mut self, label: bool) -> Self {
+        self.label = label;
+
+        self
+    }
+
+    /// Show a running block instead of the value, advanced by `tick`.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+
+        self
+    }
+
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        if self.indeterminate != indeterminate {
+            self.indeterminate = indeterminate;
+            self.phase = 0;
+            self.dirty = true;
+        }
+    }
+
+    /// Move the running block of an indeterminate bar by one pixel.
+    pub fn tick(&mut self) {
+        if self.indeterminate {
+            self.phase = self.phase.wrapping_add(1);
+            self.dirty = true;
+        }
+    }
+
+    pub fn set_value(&mut self, value: u32) {
+        let value = value.min(self.max);
+        if self.value != value {
+            self.value = value;
+            self.dirty = true;
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn set_max(&mut self, max: u32) {
+        if self.max != max {
+            self.max = max;
+            self.value = self.value.min(max);
+            self.dirty = true;
+        }
+    }
+
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    pub fn percent(&self) -> u32 {
+        self.scale(100)
+    }
+
+    /// Value scaled from `0..=max` to `0..=length`.
+    fn scale(&self, length: u32) -> u32 {
+        // Halving keeps values above `i32::MAX` in range, the lost bit is below a pixel
+        let shift = (self.max > i32::MAX as u32) as u32;
+        let (value, max) = ((self.value >> shift) as i32, (self.max >> shift) as i32);
+
+        fixed::map(value, (0, max), (0, length as i32)) as u32
+    }
+
+    /// Inner part of the bar, without the outline.
+    fn inner(&self) -> BBox {
+        BBox::new(
+            (self.bbox.start.0 + 1, self.bbox.start.1 + 1),
+            (self.bbox.end.0 - 1, self.bbox.end.1 - 1),
+        )
+    }
+
+    /// Number of pixels along the bar inside the outline.
+    fn length(&self) -> i32 {
+        let inner = self.inner();
+        let length = match self.orient {
+            Orientation::Horizontal => inner.end.0 - inner.start.0,
+            Orientation::Vertical => inner.end.1 - inner.start.1,
+        };
+
+        (length + 1).max(0)
+    }
+
+    /// Part of the inner area between `from` and `to` pixels, counted from the side the bar
+    /// grows from. `to` is exclusive.
+    fn span(&self, from: i32, to: i32) -> BBox {
+        let inner = self.inner();
+        match self.orient {
+            Orientation::Horizontal => BBox::new(
+                (inner.start.0 + from, inner.start.1),
+                (inner.start.0 + to - 1, inner.end.1),
+            ),
+            Orientation::Vertical => BBox::new(
+                (inner.start.0, inner.end.1 - to + 1),
+                (inner.end.0, inner.end.1 - from),
+            ),
+        }
+    }
+
+    /// Part of the bar which is filled, its start and end in pixels.
+    fn filled(&self) -> (i32, i32) {
+        let length = self.length();
+        if self.segments > 0 && !self.indeterminate {
+            let segments = self.segments as i32;
+            return (0, length * self.scale(self.segments) as i32 / segments);
+        }
+        if !self.indeterminate {
+            return (0, self.scale(length as u32) as i32);
+        }
+
+        // The block runs to the end and back
+        let block = (length / 4).max(1);
+        let travel = (length - block).max(1) as u32;
+        let position = (self.phase % (travel * 2)) as i32;
+        let start = if position > travel as i32 {
+            travel as i32 * 2 - position
+        } else {
+            position
+        };
+
+        (start, start + block)
+    }
+
+    fn draw_fill<C: PixelDraw>(&self, canvas: &mut C, (from, to): (i32, i32)) {
+        if from >= to {
+            return;
+        }
+
+        if self.segments == 0 || self.indeterminate {
+            Rect::new_filled(self.span(from, to), self.color).draw(canvas);
+            return;
+        }
+
+        let length = self.length();
+        let segments = self.segments as i32;
+        let filled = self.scale(self.segments) as i32;
+
+        for segment in 0..filled {
+            let start = length * segment / segments;
+            let end = length * (segment + 1) / segments;
+            // The last block fills the bar to the end
+            let gap = if segment + 1 == segments {
+                0
+            } else {
+                SEGMENT_GAP
+            };
+            if end - gap > start {
+                Rect::new_filled(self.span(start, end - gap), self.color).draw(canvas);
+            }
+        }
+    }
+
+    fn draw_label<C: PixelDraw>(&self, canvas: &mut C, (from, to): (i32, i32)) {
+        // Number followed by the percent sign
+        let mut buffer = [0; MAX_NUMBER + 1];
+        let mut number = [0; MAX_NUMBER];
+        let len = format_number(self.percent() as i32, &mut number).len();
+        buffer[..len].copy_from_slice(&number[..len]);
+        buffer[len] = b'%';
+        let label = core::str::from_utf8(&buffer[..=len]).unwrap_or("");
+        let text = |color| {
+            Text::new((0, 0), label, color).align(
+                self.inner(),
+                Alignment::Center,
+                Alignment::Center,
+            )
+        };
+
+        let mut clip = Clip::new(canvas, self.inner());
+        let mut outside = Clip::new(&mut clip, self.span(0, from));
+        text(self.color).draw(&mut outside);
+        let mut outside = Clip::new(&mut clip, self.span(to, self.length()));
+        text(self.color).draw(&mut outside);
+
+        if from < to {
+            let mut inside = Clip::new(&mut clip, self.span(from, to));
+            text(self.color.invert()).draw(&mut inside);
+        }
+    }
+}
+
+impl Drawable for ProgressBar {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        Rect::new(self.bbox, self.color).draw(canvas);
+        Rect::new_filled(self.inner(), self.color.invert()).draw(canvas);
+
+        let filled = self.filled();
+        self.draw_fill(canvas, filled);
+
+        if self.label && !self.indeterminate {
+            self.draw_label(canvas, filled);
+        }
+    }
+}
+
+impl Widget for ProgressBar {
+    fn min_size(&self) -> Size {
+        Size::new(3, 3)
+    }
+
+    fn preferred_size(&self) -> Size {
+        let thickness = if self.label {
+            FONT_5X7.height as i32 + 3
+        } else {
+            8
+        };
+
+        match self.orient {
+            Orientation::Horizontal => Size::new(64, thickness),
+            Orientation::Vertical => Size::new(thickness, 32),
+        }
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
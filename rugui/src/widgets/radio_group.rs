@@ -0,0 +1,194 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Point, Size};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Circle, Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::Widget;
+
+/// Height of an option, including padding.
+const OPTION_HEIGHT: i32 = FONT_5X7.height as i32 + 3;
+/// Gap between the radio button and its label.
+const LABEL_GAP: i32 = 4;
+
+/// Vertical list of mutually exclusive options.
+///
+/// The group captures input when focused: `Up`, `Down` and the encoder move the cursor,
+/// `Enter` selects the option under it and `Back` leaves the selection unchanged. Neither
+/// key is consumed, so a `FocusChain` releases the capture. Tapping an option selects
+/// it right away.
+pub struct RadioGroup<'a> {
+    bbox: BBox,
+    options: &'a [&'a str],
+    selected: usize,
+    cursor: usize,
+    changed: bool,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> RadioGroup<'a> {
+    pub fn new(bbox: BBox, options: &'a [&'a str], selected: usize, color: Color) -> Self {
+        let selected = selected.min(options.len().saturating_sub(1));
+
+        Self {
+            bbox,
+            options,
+            selected,
+            cursor: selected,
+            changed: false,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        }
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn set_selected(&mut self, selected: usize) {
+        let selected = selected.min(self.options.len().saturating_sub(1));
+        if self.selected != selected {
+            self.selected = selected;
+            self.cursor = selected;
+            self.dirty = true;
+        }
+    }
+
+    /// Returns the index of the option if it was selected by the user since the last call.
+    pub fn take_changed(&mut self) -> Option<usize> {
+        if core::mem::take(&mut self.changed) {
+            Some(self.selected)
+        } else {
+            None
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        self.cursor = index;
+        if self.selected != index {
+            self.selected = index;
+            self.changed = true;
+        }
+        self.dirty = true;
+    }
+}
+
+impl<'a> Drawable for RadioGroup<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+
+        let size = FONT_5X7.height as i32 + 1;
+        let mut y = bbox.start.1;
+        for (index, option) in self.options.iter().enumerate() {
+            if y + OPTION_HEIGHT - 1 > bbox.end.1 {
+                break;
+            }
+
+            let button = BBox::from_relative((bbox.start.0, y + 1), (size, size));
+            Circle::from_bbox(button, self.color).draw(canvas);
+            if index == self.selected {
+                let dot = BBox::new(
+                    (button.start.0 + 2, button.start.1 + 2),
+                    (button.end.0 - 2, button.end.1 - 2),
+                );
+                Circle::from_bbox(dot, self.color).filled(true).draw(canvas);
+            }
+
+            let label = BBox::new(
+                (button.end.0 + LABEL_GAP, y),
+                (bbox.end.0, y + OPTION_HEIGHT - 1),
+            );
+            let mut color = self.color;
+            if self.focus == Focus::Editing && index == self.cursor {
+                Rect::new_filled(label, self.color).draw(canvas);
+                color = self.color.invert();
+            }
+
+            let mut clip = Clip::new(canvas, label);
+            Text::new((label.start.0 + 1, y + 2), option, color).draw(&mut clip);
+
+            y += OPTION_HEIGHT;
+        }
+
+        if self.focus == Focus::Focused {
+            Rect::new(bbox, self.color).draw(canvas);
+        }
+    }
+}
+
+impl<'a> Widget for RadioGroup<'a> {
+    fn min_size(&self) -> Size {
+        let widest = self
+            .options
+            .iter()
+            .map(|option| FONT_5X7.text_size(option).0);
+        let width = FONT_5X7.height as i32 + 1 + LABEL_GAP + widest.max().unwrap_or(0) + 2;
+
+        Size::new(width, OPTION_HEIGHT * self.options.len().max(1) as i32 - 1)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_editable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.cursor = self.selected;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let last = self.options.len().saturating_sub(1) as isize;
+        let step = match event {
+            Event::KeyPress(Key::Down) | Event::KeyRepeat(Key::Down) => 1,
+            Event::KeyPress(Key::Up) | Event::KeyRepeat(Key::Up) => -1,
+            Event::Encoder(steps) => *steps as isize,
+            // Not consumed, so that a focus chain stops editing
+            Event::KeyPress(Key::Enter | Key::EncoderPush) => {
+                self.select(self.cursor);
+                return false;
+            }
+            Event::TouchDown(Point { y, .. }) => {
+                let index = (y - self.bbox.start.1) / OPTION_HEIGHT;
+                if index < 0 || index as isize > last {
+                    return false;
+                }
+
+                self.select(index as usize);
+                return true;
+            }
+            _ => return false,
+        };
+
+        self.cursor = (self.cursor as isize + step).clamp(0, last) as usize;
+        self.dirty = true;
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
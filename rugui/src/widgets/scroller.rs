@@ -0,0 +1,149 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::Size;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Line, Rect};
+use crate::widgets::Widget;
+
+/// Minimal length of the scroller thumb in pixels, so it stays visible for long contents.
+const MIN_THUMB: u32 = 3;
+
+pub struct Scroller {
+    bbox: BBox,
+    offset: u32,
+    viewport: u32,
+    content: u32,
+    orient: Orientation,
+    color: Color,
+    dirty: bool,
+}
+
+impl Scroller {
+    /// `content` is the length of the whole scrollable content, `viewport` is the length
+    /// of its visible part and `offset` is the position of the viewport within the content.
+    /// All of them are in the same units, e.g. pixels or list items.
+    pub fn new(
+        bbox: BBox,
+        offset: u32,
+        viewport: u32,
+        content: u32,
+        orient: Orientation,
+        color: Color,
+    ) -> Self {
+        let viewport = viewport.min(content);
+
+        Self {
+            bbox,
+            offset: offset.min(content - viewport),
+            viewport,
+            content,
+            orient,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Move the viewport within the content. Clamped to the content length.
+    pub fn set_offset(&mut self, offset: u32) {
+        let offset = offset.min(self.content - self.viewport);
+        if self.offset != offset {
+            self.offset = offset;
+            self.dirty = true;
+        }
+    }
+
+    pub fn set_content(&mut self, viewport: u32, content: u32) {
+        self.content = content;
+        self.viewport = viewport.min(content);
+        self.offset = self.offset.min(content - self.viewport);
+        self.dirty = true;
+    }
+
+    /// Bounding box of the scroller thumb.
+    pub fn thumb(&self) -> BBox {
+        let (start, end) = match self.orient {
+            Orientation::Vertical => (self.bbox.start.1, self.bbox.end.1),
+            Orientation::Horizontal => (self.bbox.start.0, self.bbox.end.0),
+        };
+        let track = (end - start + 1) as u32;
+
+        let (length, position) = if self.content == 0 || self.viewport == self.content {
+            (track, 0)
+        } else {
+            // Products of two u32 don't fit into u32, the results are within the track
+            let (track, viewport, content) =
+                (track as u64, self.viewport as u64, self.content as u64);
+            let length = (track * viewport / content).clamp((MIN_THUMB as u64).min(track), track);
+            let position = (track - length) * self.offset as u64 / (content - viewport);
+            (length as u32, position as u32)
+        };
+
+        let thumb_start = start + position as i32;
+        let thumb_end = thumb_start + length as i32 - 1;
+
+        match self.orient {
+            Orientation::Vertical => BBox::new(
+                (self.bbox.start.0, thumb_start),
+                (self.bbox.end.0, thumb_end),
+            ),
+            Orientation::Horizontal => BBox::new(
+                (thumb_start, self.bbox.start.1),
+                (thumb_end, self.bbox.end.1),
+            ),
+        }
+    }
+}
+
+impl Drawable for Scroller {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, Color::White).draw(canvas);
+
+        match self.orient {
+            Orientation::Vertical => {
+                let mid_x = bbox.start.0 + bbox.width() as i32 / 2;
+                let track = BBox::new((mid_x, bbox.start.1), (mid_x, bbox.end.1));
+                Line::new_vertical(track, self.color).draw(canvas);
+            }
+            Orientation::Horizontal => {
+                let mid_y = bbox.start.1 + bbox.height() as i32 / 2;
+                let track = BBox::new((bbox.start.0, mid_y), (bbox.end.0, mid_y));
+                Line::new(track, self.color).draw(canvas);
+            }
+        }
+
+        Rect::new_filled(self.thumb(), self.color).draw(canvas);
+    }
+}
+
+impl Widget for Scroller {
+    fn min_size(&self) -> Size {
+        match self.orient {
+            Orientation::Vertical => Size::new(1, MIN_THUMB as i32),
+            Orientation::Horizontal => Size::new(MIN_THUMB as i32, 1),
+        }
+    }
+
+    fn preferred_size(&self) -> Size {
+        match self.orient {
+            Orientation::Vertical => Size::new(5, 32),
+            Orientation::Horizontal => Size::new(32, 5),
+        }
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
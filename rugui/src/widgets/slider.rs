@@ -0,0 +1,266 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Point, Size};
+use crate::fixed::map;
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::widgets::Widget;
+
+/// Thickness of the knob along the slider.
+const KNOB_WIDTH: i32 = 3;
+/// Gap between the edge of the widget and the track across the slider.
+const TRACK_INSET: i32 = 2;
+
+/// Selects a value from a range by moving a knob along a filled track.
+///
+/// The slider captures input when focused: arrows and the encoder change the value
+/// by `step`. Touching or dragging over the track sets the value under the finger.
+/// Horizontal slider grows to the right, vertical one grows to the top.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::input::Event;
+/// # use rugui::widgets::{Slider, Widget};
+/// let mut slider = Slider::new(BBox::new((0, 0), (63, 8)), 0, 100, 50, Color::Black).step(5);
+///
+/// slider.handle_event(&Event::Encoder(2));
+/// assert_eq!(slider.take_changed(), Some(60));
+/// ```
+pub struct Slider {
+    bbox: BBox,
+    min: i32,
+    max: i32,
+    step: i32,
+    value: i32,
+    orient: Orientation,
+    changed: bool,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+impl Slider {
+    pub fn new(bbox: BBox, min: i32, max: i32, value: i32, color: Color) -> Self {
+        let max = max.max(min);
+
+        Self {
+            bbox,
+            min,
+            max,
+            step: 1,
+            value: value.clamp(min, max),
+            orient: Orientation::Horizontal,
+            changed: false,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Change of the value by a single key press or encoder step.
+    pub fn step(mut self, step: i32) -> Self {
+        self.step = step.max(1);
+
+        self
+    }
+
+    pub fn orientation(mut self, orient: Orientation) -> Self {
+        self.orient = orient;
+
+        self
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Set the value without reporting a change. Clamped to the range.
+    pub fn set_value(&mut self, value: i32) {
+        let value = value.clamp(self.min, self.max);
+        if self.value != value {
+            self.value = value;
+            self.dirty = true;
+        }
+    }
+
+    /// Returns the value if it was changed by the user since the last call.
+    pub fn take_changed(&mut self) -> Option<i32> {
+        if core::mem::take(&mut self.changed) {
+            Some(self.value)
+        } else {
+            None
+        }
+    }
+
+    fn change(&mut self, value: i32) {
+        let value = value.clamp(self.min, self.max);
+        if self.value != value {
+            self.value = value;
+            self.changed = true;
+            self.dirty = true;
+        }
+    }
+
+    /// Start and end of the track along the slider.
+    fn track(&self) -> (i32, i32) {
+        match self.orient {
+            Orientation::Horizontal => (self.bbox.start.0, self.bbox.end.0),
+            Orientation::Vertical => (self.bbox.start.1, self.bbox.end.1),
+        }
+    }
+
+    /// Number of filled pixels inside the track outline.
+    fn filled(&self) -> i32 {
+        let (start, end) = self.track();
+        let length = (end - start - 1).max(0);
+
+        map(self.value, (self.min, self.max), (0, length))
+    }
+
+    /// Value under the touch point, rounded to the nearest step.
+    fn value_at(&self, Point { x, y }: Point) -> i32 {
+        let (start, end) = self.track();
+        let length = (end - start - 1).max(1) as i64;
+        let offset = match self.orient {
+            Orientation::Horizontal => x - start,
+            Orientation::Vertical => end - y,
+        };
+
+        let range = self.max as i64 - self.min as i64;
+        let value = (offset.clamp(0, length as i32) as i64 * range + length / 2) / length;
+        let step = self.step as i64;
+        let value = (value + step / 2) / step * step;
+
+        (self.min as i64 + value.min(range)) as i32
+    }
+}
+
+impl Drawable for Slider {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+
+        let (start, end) = self.track();
+        let filled = self.filled();
+        // Knob is centered on the last filled pixel
+        let knob = match self.orient {
+            Orientation::Horizontal => start + filled,
+            Orientation::Vertical => end - filled,
+        }
+        .clamp(start + 1, (end - 1).max(start + 1));
+
+        let (track, fill, knob) = match self.orient {
+            Orientation::Horizontal => {
+                let (top, bottom) = inset(bbox.start.1, bbox.end.1);
+                (
+                    BBox::new((start, top), (end, bottom)),
+                    BBox::new((start + 1, top + 1), (start + filled, bottom - 1)),
+                    BBox::new(
+                        (knob - KNOB_WIDTH / 2, bbox.start.1),
+                        (knob + KNOB_WIDTH / 2, bbox.end.1),
+                    ),
+                )
+            }
+            Orientation::Vertical => {
+                let (left, right) = inset(bbox.start.0, bbox.end.0);
+                (
+                    BBox::new((left, start), (right, end)),
+                    BBox::new((left + 1, end - filled), (right - 1, end - 1)),
+                    BBox::new(
+                        (bbox.start.0, knob - KNOB_WIDTH / 2),
+                        (bbox.end.0, knob + KNOB_WIDTH / 2),
+                    ),
+                )
+            }
+        };
+
+        Rect::new(track, self.color).draw(canvas);
+        if filled > 0 {
+            Rect::new_filled(fill, self.color).draw(canvas);
+        }
+
+        // The knob is shown only when the slider is focused, filled while editing
+        match self.focus {
+            Focus::None => {}
+            Focus::Focused => {
+                Rect::new_filled(knob, self.color.invert()).draw(canvas);
+                Rect::new(knob, self.color).draw(canvas);
+            }
+            Focus::Editing => Rect::new_filled(knob, self.color).draw(canvas),
+        }
+    }
+}
+
+/// Track across the slider, leaving space for the knob if the widget is thick enough.
+fn inset(start: i32, end: i32) -> (i32, i32) {
+    if end - start > TRACK_INSET * 2 + 1 {
+        (start + TRACK_INSET, end - TRACK_INSET)
+    } else {
+        (start, end)
+    }
+}
+
+impl Widget for Slider {
+    fn min_size(&self) -> Size {
+        match self.orient {
+            Orientation::Horizontal => Size::new(KNOB_WIDTH * 2, 2),
+            Orientation::Vertical => Size::new(2, KNOB_WIDTH * 2),
+        }
+    }
+
+    fn preferred_size(&self) -> Size {
+        match self.orient {
+            Orientation::Horizontal => Size::new(63, 8),
+            Orientation::Vertical => Size::new(8, 31),
+        }
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_editable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let steps = match event {
+            Event::KeyPress(Key::Up | Key::Right) | Event::KeyRepeat(Key::Up | Key::Right) => 1,
+            Event::KeyPress(Key::Down | Key::Left) | Event::KeyRepeat(Key::Down | Key::Left) => -1,
+            Event::Encoder(steps) => *steps as i32,
+            Event::TouchDown(point) | Event::TouchMove(point) => {
+                self.change(self.value_at(*point));
+                return true;
+            }
+            _ => return false,
+        };
+
+        self.change(self.value.saturating_add(steps.saturating_mul(self.step)));
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
@@ -0,0 +1,287 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::Size;
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::Widget;
+
+/// Maximal number of digits, so that every value fits into `i32`.
+const MAX_DIGITS: u32 = 9;
+/// Sign, digits and decimal point.
+const MAX_CHARS: usize = MAX_DIGITS as usize + 2;
+
+/// Numeric field which is edited digit by digit.
+///
+/// The value is an integer, `decimals` places the decimal point: the value `215` with
+/// one decimal is shown as `21.5`. When the field captures input, the most significant
+/// digit is highlighted. `Up`, `Down` and the encoder change the highlighted digit,
+/// `Left` and `Right` move the highlight, `Enter` moves it to the next digit and leaves
+/// the field after the last one. `Back` restores the value the editing started with.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::focus::Focus;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::input::{Event, Key};
+/// # use rugui::widgets::{SpinBox, Widget};
+/// let bbox = BBox::new((0, 0), (47, 10));
+/// let mut spin = SpinBox::new(bbox, 215, 0, 999, Color::Black).decimals(1).unit("C");
+///
+/// spin.set_focus(Focus::Editing);
+/// spin.handle_event(&Event::KeyPress(Key::Right));
+/// spin.handle_event(&Event::Encoder(2));
+/// assert_eq!(spin.take_changed(), Some(235));
+/// ```
+pub struct SpinBox<'a> {
+    bbox: BBox,
+    value: i32,
+    min: i32,
+    max: i32,
+    digits: u32,
+    decimals: u32,
+    unit: &'a str,
+    /// Position of the edited digit, zero is the least significant one
+    cursor: u32,
+    /// Value before editing, restored by `Back`
+    original: i32,
+    changed: bool,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> SpinBox<'a> {
+    /// The number of digits is chosen to fit both `min` and `max`.
+    pub fn new(bbox: BBox, value: i32, min: i32, max: i32, color: Color) -> Self {
+        let max = max.max(min);
+        let widest = min.unsigned_abs().max(max.unsigned_abs());
+        let digits = (widest.checked_ilog10().unwrap_or(0) + 1).min(MAX_DIGITS);
+        let value = value.clamp(min, max);
+
+        Self {
+            bbox,
+            value,
+            min,
+            max,
+            digits,
+            decimals: 0,
+            unit: "",
+            cursor: digits - 1,
+            original: value,
+            changed: false,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Number of digits after the decimal point.
+    pub fn decimals(mut self, decimals: u32) -> Self {
+        self.decimals = decimals.min(MAX_DIGITS - 1);
+        self.digits = self.digits.max(self.decimals + 1);
+        self.cursor = self.digits - 1;
+
+        self
+    }
+
+    /// Unit shown after the value, e.g. `"V"`.
+    pub fn unit(mut self, unit: &'a str) -> Self {
+        self.unit = unit;
+
+        self
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Set the value without reporting a change. Clamped to the range.
+    pub fn set_value(&mut self, value: i32) {
+        let value = value.clamp(self.min, self.max);
+        if self.value != value {
+            self.value = value;
+            self.dirty = true;
+        }
+    }
+
+    /// Returns the value if it was changed by the user since the last call.
+    pub fn take_changed(&mut self) -> Option<i32> {
+        if core::mem::take(&mut self.changed) {
+            Some(self.value)
+        } else {
+            None
+        }
+    }
+
+    fn change(&mut self, value: i32) {
+        let value = value.clamp(self.min, self.max);
+        if self.value != value {
+            self.value = value;
+            self.changed = true;
+        }
+        self.dirty = true;
+    }
+
+    /// Format the value into the buffer. Leading zeros are kept while editing,
+    /// so every digit can be highlighted.
+    fn format<'b>(&self, buffer: &'b mut [u8; MAX_CHARS]) -> &'b str {
+        let mut len = 0;
+        if self.min < 0 {
+            buffer[len] = if self.value < 0 { b'-' } else { b' ' };
+            len += 1;
+        }
+
+        let value = self.value.unsigned_abs();
+        let mut leading = self.focus != Focus::Editing;
+        for position in (0..self.digits).rev() {
+            if position + 1 == self.decimals {
+                buffer[len] = b'.';
+                len += 1;
+            }
+
+            let digit = (value / 10u32.pow(position) % 10) as u8;
+            leading &= digit == 0 && position > self.decimals;
+            buffer[len] = if leading { b' ' } else { b'0' + digit };
+            len += 1;
+        }
+
+        core::str::from_utf8(&buffer[..len]).unwrap_or("")
+    }
+
+    /// Index of the character which shows the digit at the `position`.
+    fn char_index(&self, position: u32) -> u32 {
+        let sign = (self.min < 0) as u32;
+        let point = (self.decimals > 0 && position < self.decimals) as u32;
+
+        sign + self.digits - 1 - position + point
+    }
+
+    fn text_width(&self) -> i32 {
+        let mut buffer = [0; MAX_CHARS];
+        let mut chars = self.format(&mut buffer).len() as u32;
+        if !self.unit.is_empty() {
+            chars += 1 + self.unit.chars().count() as u32;
+        }
+
+        (chars * FONT_5X7.advance()).saturating_sub(FONT_5X7.spacing) as i32
+    }
+}
+
+impl<'a> Drawable for SpinBox<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+        if self.focus != Focus::None {
+            Rect::new(bbox, self.color).draw(canvas);
+        }
+
+        let mut clip = Clip::new(canvas, bbox);
+        let x = bbox.start.0 + 2;
+        let y = bbox.start.1 + (bbox.height() as i32 + 1 - FONT_5X7.height as i32) / 2;
+        let advance = FONT_5X7.advance() as i32;
+
+        let mut buffer = [0; MAX_CHARS];
+        let number = self.format(&mut buffer);
+
+        if self.focus == Focus::Editing {
+            let cell = x + self.char_index(self.cursor) as i32 * advance;
+            let highlight = BBox::new(
+                (cell - 1, y - 1),
+                (cell + FONT_5X7.width as i32, y + FONT_5X7.height as i32),
+            );
+            Rect::new_filled(highlight, self.color).draw(&mut clip);
+        }
+
+        // Characters are drawn one by one, so the highlighted one can be inverted
+        for (index, c) in number.char_indices() {
+            let mut color = self.color;
+            if self.focus == Focus::Editing && index as u32 == self.char_index(self.cursor) {
+                color = self.color.invert();
+            }
+
+            let position = (x + index as i32 * advance, y);
+            Text::new(position, &number[index..index + c.len_utf8()], color).draw(&mut clip);
+        }
+
+        let unit_x = x + (number.len() as i32 + 1) * advance;
+        Text::new((unit_x, y), self.unit, self.color).draw(&mut clip);
+    }
+}
+
+impl<'a> Widget for SpinBox<'a> {
+    fn min_size(&self) -> Size {
+        Size::new(self.text_width() + 3, FONT_5X7.height as i32 + 3)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_editable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        if focus == Focus::Editing && self.focus != Focus::Editing {
+            self.original = self.value;
+            self.cursor = self.digits - 1;
+        }
+
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let steps = match event {
+            Event::KeyPress(Key::Up) | Event::KeyRepeat(Key::Up) => 1,
+            Event::KeyPress(Key::Down) | Event::KeyRepeat(Key::Down) => -1,
+            Event::Encoder(steps) => *steps as i32,
+            Event::KeyPress(Key::Left) if self.cursor + 1 < self.digits => {
+                self.cursor += 1;
+                self.dirty = true;
+                return true;
+            }
+            Event::KeyPress(Key::Right) if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.dirty = true;
+                return true;
+            }
+            // Move to the next digit, the last one finishes editing
+            Event::KeyPress(Key::Enter | Key::EncoderPush) if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.dirty = true;
+                return true;
+            }
+            // Not consumed, so that a focus chain stops editing
+            Event::KeyPress(Key::Back) if self.focus == Focus::Editing => {
+                self.change(self.original);
+                return false;
+            }
+            _ => return false,
+        };
+
+        let delta = steps.saturating_mul(10i32.pow(self.cursor));
+        self.change(self.value.saturating_add(delta));
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
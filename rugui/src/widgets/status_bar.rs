@@ -0,0 +1,609 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::Size;
+use crate::framebuffer::*;
+use crate::geometry::{Bitmap, Drawable, Line, Rect};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::{Widget, WidgetList};
+
+/// Gap between items of a status bar.
+const ITEM_SPACING: i32 = 3;
+/// Width of a signal strength bar, bars are separated by one pixel.
+const BAR_WIDTH: i32 = 2;
+
+#[rustfmt::skip]
+static WIFI_DATA: [u8; 6] = [
+    0b0111_1100,
+    0b1000_0010,
+    0b0011_1000,
+    0b0100_0100,
+    0b0000_0000,
+    0b0001_0000,
+];
+
+#[rustfmt::skip]
+static BLUETOOTH_DATA: [u8; 7] = [
+    0b0011_0000,
+    0b1010_1000,
+    0b0111_0000,
+    0b0010_0000,
+    0b0111_0000,
+    0b1010_1000,
+    0b0011_0000,
+];
+
+#[rustfmt::skip]
+static BOLT_DATA: [u8; 5] = [
+    0b0011_0000,
+    0b0110_0000,
+    0b1111_1000,
+    0b0011_0000,
+    0b0110_0000,
+];
+
+/// Wi-Fi icon for a `StatusIcon`.
+pub const WIFI_ICON: Bitmap<'static> = Bitmap::new(7, 6, &WIFI_DATA, Color::Black);
+/// Bluetooth icon for a `StatusIcon`.
+pub const BLUETOOTH_ICON: Bitmap<'static> = Bitmap::new(5, 7, &BLUETOOTH_DATA, Color::Black);
+const BOLT_ICON: Bitmap<'static> = Bitmap::new(5, 5, &BOLT_DATA, Color::Black);
+
+/// Battery with the charge shown by levels and a bolt while charging.
+pub struct Battery {
+    bbox: BBox,
+    percent: u8,
+    levels: u8,
+    charging: bool,
+    color: Color,
+    dirty: bool,
+}
+
+impl Battery {
+    pub fn new(bbox: BBox, percent: u8, color: Color) -> Self {
+        Self {
+            bbox,
+            percent: percent.min(100),
+            levels: 4,
+            charging: false,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Number of blocks showing the charge. Zero shows it continuously.
+    pub fn levels(mut self, levels: u8) -> Self {
+        self.levels = levels;
+
+        self
+    }
+
+    pub fn set_percent(&mut self, percent: u8) {
+        let percent = percent.min(100);
+        if self.percent != percent {
+            self.percent = percent;
+            self.dirty = true;
+        }
+    }
+
+    pub fn percent(&self) -> u8 {
+        self.percent
+    }
+
+    pub fn set_charging(&mut self, charging: bool) {
+        if self.charging != charging {
+            self.charging = charging;
+            self.dirty = true;
+        }
+    }
+
+    pub fn is_charging(&self) -> bool {
+        self.charging
+    }
+
+    /// Number of shown levels, rounded up so that an almost empty battery isn't blank.
+    pub fn level(&self) -> u8 {
+        let levels = self.levels.max(1) as u32;
+
+        ((self.percent as u32 * levels).div_ceil(100)) as u8
+    }
+}
+
+impl Drawable for Battery {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+
+        // The terminal is a third of the height on the right
+        let body = BBox::new(bbox.start, (bbox.end.0 - 1, bbox.end.1));
+        let terminal = bbox.height() as i32 / 3;
+        Line::new_vertical(
+            BBox::new(
+                (bbox.end.0, bbox.start.1 + terminal),
+                (bbox.end.0, bbox.end.1 - terminal),
+            ),
+            self.color,
+        )
+        .draw(canvas);
+        Rect::new(body, self.color).draw(canvas);
+
+        let inner = BBox::new(
+            (body.start.0 + 2, body.start.1 + 2),
+            (body.end.0 - 2, body.end.1 - 2),
+        );
+        let width = inner.end.0 - inner.start.0 + 1;
+        let block = |start: i32, end: i32| {
+            BBox::new(
+                (inner.start.0 + start, inner.start.1),
+                (inner.start.0 + end - 1, inner.end.1),
+            )
+        };
+
+        let filled = if self.levels == 0 {
+            let filled = width * self.percent as i32 / 100;
+            if filled > 0 {
+                Rect::new_filled(block(0, filled), self.color).draw(canvas);
+            }
+            filled
+        } else {
+            let levels = self.levels as i32;
+            let level = self.level() as i32;
+            for index in 0..level {
+                let start = width * index / levels;
+                let end = width * (index + 1) / levels;
+                // Blocks are separated by one pixel, except the last one and under the bolt
+                let end = if index + 1 == levels || self.charging {
+                    end
+                } else {
+                    end - 1
+                };
+                if end > start {
+                    Rect::new_filled(block(start, end), self.color).draw(canvas);
+                }
+            }
+            width * level / levels
+        };
+
+        if self.charging {
+            // The bolt is inverted over the filled part
+            let (bolt_width, bolt_height) = BOLT_ICON.size();
+            let position = (
+                body.start.0 + (body.width() as i32 + 1 - bolt_width) / 2,
+                body.start.1 + (body.height() as i32 + 1 - bolt_height) / 2,
+            );
+            let split = inner.start.0 + filled;
+            let (full, empty) = body.split(Axis::X, split);
+
+            let bolt = BOLT_ICON.at(position);
+            bolt.color(self.color.invert())
+                .draw(&mut Clip::new(canvas, full));
+            bolt.color(self.color).draw(&mut Clip::new(canvas, empty));
+        }
+    }
+}
+
+impl Widget for Battery {
+    fn min_size(&self) -> Size {
+        Size::new(13, 6)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
+
+/// Signal strength shown by bars of growing height. Bars above the level are shown
+/// as dots at the bottom.
+pub struct SignalStrength {
+    bbox: BBox,
+    level: u8,
+    bars: u8,
+    color: Color,
+    dirty: bool,
+}
+
+impl SignalStrength {
+    pub fn new(bbox: BBox, level: u8, color: Color) -> Self {
+        Self {
+            bbox,
+            level: level.min(4),
+            bars: 4,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Number of bars, the level is clamped to it.
+    pub fn bars(mut self, bars: u8) -> Self {
+        self.bars = bars.max(1);
+        self.level = self.level.min(self.bars);
+
+        self
+    }
+
+    pub fn set_level(&mut self, level: u8) {
+        let level = level.min(self.bars);
+        if self.level != level {
+            self.level = level;
+            self.dirty = true;
+        }
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+}
+
+impl Drawable for SignalStrength {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+
+        let bars = self.bars as i32;
+        let height = bbox.height() as i32 + 1;
+        for bar in 0..bars {
+            let x = bbox.start.0 + bar * (BAR_WIDTH + 1);
+            // Even the lowest bar is taller than the stub of an empty one
+            let top = if bar < self.level as i32 {
+                bbox.end.1 + 1 - (height * (bar + 1) / bars).max(2)
+            } else {
+                bbox.end.1
+            };
+
+            let bar = BBox::new((x, top), (x + BAR_WIDTH - 1, bbox.end.1));
+            Rect::new_filled(bar, self.color).draw(canvas);
+        }
+    }
+}
+
+impl Widget for SignalStrength {
+    fn min_size(&self) -> Size {
+        Size::new(self.bars as i32 * (BAR_WIDTH + 1) - 2, 6)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
+
+/// Icon which can be hidden, e.g. `WIFI_ICON` while connected.
+pub struct StatusIcon<'a> {
+    bbox: BBox,
+    icon: Bitmap<'a>,
+    visible: bool,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> StatusIcon<'a> {
+    pub fn new(bbox: BBox, icon: Bitmap<'a>, color: Color) -> Self {
+        Self {
+            bbox,
+            icon,
+            visible: true,
+            color,
+            dirty: true,
+        }
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        if self.visible != visible {
+            self.visible = visible;
+            self.dirty = true;
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
+impl<'a> Drawable for StatusIcon<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+
+        if self.visible {
+            let (width, height) = self.icon.size();
+            let position = (
+                bbox.start.0 + (bbox.width() as i32 + 1 - width) / 2,
+                bbox.start.1 + (bbox.height() as i32 + 1 - height) / 2,
+            );
+            self.icon
+                .at(position)
+                .color(self.color)
+                .draw(&mut Clip::new(canvas, bbox));
+        }
+    }
+}
+
+impl<'a> Widget for StatusIcon<'a> {
+    fn min_size(&self) -> Size {
+        let (width, height) = self.icon.size();
+
+        Size::new(width - 1, height - 1)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
+
+/// Time of the day as `HH:MM` or `HH:MM:SS`.
+pub struct Clock {
+    bbox: BBox,
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+    show_seconds: bool,
+    color: Color,
+    dirty: bool,
+}
+
+impl Clock {
+    pub fn new(bbox: BBox, color: Color) -> Self {
+        Self {
+            bbox,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            show_seconds: false,
+            color,
+            dirty: true,
+        }
+    }
+
+    pub fn seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+
+        self
+    }
+
+    /// Values out of range wrap around, e.g. 24 hours are shown as `00`.
+    pub fn set_time(&mut self, hours: u8, minutes: u8, seconds: u8) {
+        let time = (hours % 24, minutes % 60, seconds % 60);
+        let changed = if self.show_seconds {
+            self.time() != time
+        } else {
+            (self.hours, self.minutes) != (time.0, time.1)
+        };
+
+        (self.hours, self.minutes, self.seconds) = time;
+        self.dirty |= changed;
+    }
+
+    pub fn time(&self) -> (u8, u8, u8) {
+        (self.hours, self.minutes, self.seconds)
+    }
+
+    fn format<'b>(&self, buffer: &'b mut [u8; 8]) -> &'b str {
+        let mut len = 0;
+        for (index, value) in [self.hours, self.minutes, self.seconds].iter().enumerate() {
+            if index == 2 && !self.show_seconds {
+                break;
+            }
+            if index > 0 {
+                buffer[len] = b':';
+                len += 1;
+            }
+
+            buffer[len] = b'0' + value / 10;
+            buffer[len + 1] = b'0' + value % 10;
+            len += 2;
+        }
+
+        core::str::from_utf8(&buffer[..len]).unwrap_or("")
+    }
+}
+
+impl Drawable for Clock {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        Rect::new_filled(self.bbox, self.color.invert()).draw(canvas);
+
+        let mut buffer = [0; 8];
+        let mut clip = Clip::new(canvas, self.bbox);
+        Text::new((0, 0), self.format(&mut buffer), self.color)
+            .align(self.bbox, Alignment::Center, Alignment::Center)
+            .draw(&mut clip);
+    }
+}
+
+impl Widget for Clock {
+    fn min_size(&self) -> Size {
+        let mut buffer = [0; 8];
+        let (width, height) = FONT_5X7.text_size(self.format(&mut buffer));
+
+        Size::new(width - 1, height - 1)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
+
+/// Strip with items aligned to its right edge, e.g. the top row of the screen.
+///
+/// Items are a `WidgetList`, e.g. a tuple of different items. They keep their order
+/// from left to right and are vertically centered. Call `layout` after changing an
+/// item so that its size changes.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::widgets::*;
+/// let zero = BBox::new((0, 0), (0, 0));
+/// let mut bar = StatusBar::new(
+///     BBox::new((0, 0), (127, 7)),
+///     (
+///         StatusIcon::new(zero, WIFI_ICON, Color::Black),
+///         Battery::new(zero, 80, Color::Black),
+///     ),
+///     Color::Black,
+/// );
+///
+/// bar.items_mut().1.set_charging(true);
+/// assert_eq!(bar.items().1.bbox().end, (127, 6));
+/// ```
+pub struct StatusBar<L: WidgetList> {
+    bbox: BBox,
+    items: L,
+    spacing: i32,
+    color: Color,
+    dirty: bool,
+}
+
+impl<L: WidgetList> StatusBar<L> {
+    pub fn new(bbox: BBox, items: L, color: Color) -> Self {
+        let mut bar = Self {
+            bbox,
+            items,
+            spacing: ITEM_SPACING,
+            color,
+            dirty: true,
+        };
+        bar.layout();
+
+        bar
+    }
+
+    pub fn spacing(mut self, spacing: i32) -> Self {
+        self.spacing = spacing;
+        self.layout();
+
+        self
+    }
+
+    pub fn items(&self) -> &L {
+        &self.items
+    }
+
+    pub fn items_mut(&mut self) -> &mut L {
+        &mut self.items
+    }
+
+    /// Place the items from the right edge by their minimal sizes.
+    pub fn layout(&mut self) {
+        let bbox = self.bbox;
+        let mut right = bbox.end.0;
+
+        for index in (0..self.items.len()).rev() {
+            let Size { width, height } = self.items.min_size(index);
+            let height = height.min(bbox.height() as i32);
+            let top = bbox.start.1 + (bbox.height() as i32 - height) / 2;
+
+            let item = BBox::new((right - width, top), (right, top + height));
+            self.items.set_bbox(index, item);
+            right -= width + 1 + self.spacing;
+        }
+
+        self.dirty = true;
+    }
+}
+
+impl<L: WidgetList> Drawable for StatusBar<L> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        Rect::new_filled(self.bbox, self.color.invert()).draw(canvas);
+
+        let mut clip = Clip::new(canvas, self.bbox);
+        for index in 0..self.items.len() {
+            self.items.draw_widget(index, &mut clip);
+        }
+    }
+}
+
+impl<L: WidgetList> Widget for StatusBar<L> {
+    fn min_size(&self) -> Size {
+        let items = 0..self.items.len();
+        let widths = items.clone().map(|i| self.items.min_size(i).width + 1);
+        let height = items.map(|i| self.items.min_size(i).height).max();
+        let gaps = self.spacing * self.items.len().saturating_sub(1) as i32;
+
+        Size::new(widths.sum::<i32>() + gaps - 1, height.unwrap_or(0))
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.layout();
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty || (0..self.items.len()).any(|i| self.items.needs_redraw(i))
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+        for index in 0..self.items.len() {
+            self.items.mark_drawn(index);
+        }
+    }
+
+    /// Only the changed items are drawn, unless the whole bar was laid out again.
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        if self.dirty {
+            self.draw(canvas);
+            self.mark_drawn();
+            return true;
+        }
+
+        let mut clip = Clip::new(canvas, self.bbox);
+        let mut drawn = false;
+        for index in 0..self.items.len() {
+            drawn |= self.items.redraw_widget(index, &mut clip);
+        }
+
+        drawn
+    }
+}
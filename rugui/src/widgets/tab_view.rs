@@ -0,0 +1,319 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Point, Size};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Line, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::{Widget, WidgetList};
+
+/// Space between a tab label and the tab edges.
+const TAB_PADDING: i32 = 3;
+/// Height of a tab, including the padding.
+const TAB_HEIGHT: i32 = FONT_5X7.height as i32 + 4;
+
+/// Row of tab labels which switches the content area between pages.
+///
+/// `Orientation::Horizontal` puts the tabs in a row above the content, `Vertical` in
+/// a column left to it. Only the current page is drawn and receives events. A tap on
+/// a tab selects it. While the view captures input, arrows and the encoder switch the
+/// tabs. Pages are a `WidgetList` with a page for each label, so they can be different
+/// widgets.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::coordinates::cvec::Point;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::input::Event;
+/// # use rugui::widgets::{Gauge, ProgressBar, TabView, Widget};
+/// let zero = BBox::new((0, 0), (0, 0));
+/// let pages = (
+///     Gauge::new(zero, 0, 100, 10, Color::Black),
+///     ProgressBar::new(zero, 90, 100, Color::Black),
+/// );
+/// let mut tabs = TabView::new(BBox::new((0, 0), (127, 63)), ["CPU", "Disk"], pages, Color::Black);
+///
+/// tabs.handle_event(&Event::TouchDown(Point::new(100, 3)));
+/// assert_eq!(tabs.take_changed(), Some(1));
+/// assert_eq!(tabs.pages().1.value(), 90);
+/// ```
+pub struct TabView<'a, L: WidgetList, const N: usize> {
+    bbox: BBox,
+    labels: [&'a str; N],
+    pages: L,
+    current: usize,
+    orient: Orientation,
+    changed: bool,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a, L: WidgetList, const N: usize> TabView<'a, L, N> {
+    /// Pages are placed into the content area, the first one is shown.
+    pub fn new(bbox: BBox, labels: [&'a str; N], pages: L, color: Color) -> Self {
+        let mut view = Self {
+            bbox,
+            labels,
+            pages,
+            current: 0,
+            orient: Orientation::Horizontal,
+            changed: false,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        };
+        view.layout();
+
+        view
+    }
+
+    /// Placement of the tabs: a row above the content or a column left to it.
+    pub fn orientation(mut self, orient: Orientation) -> Self {
+        self.orient = orient;
+        self.layout();
+
+        self
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Show the page without reporting a change.
+    pub fn set_current(&mut self, index: usize) {
+        if index < N && self.current != index {
+            self.current = index;
+            self.dirty = true;
+        }
+    }
+
+    /// Returns the index of the current page if the user switched it since the last call.
+    pub fn take_changed(&mut self) -> Option<usize> {
+        if core::mem::take(&mut self.changed) {
+            Some(self.current)
+        } else {
+            None
+        }
+    }
+
+    pub fn pages(&self) -> &L {
+        &self.pages
+    }
+
+    pub fn pages_mut(&mut self) -> &mut L {
+        &mut self.pages
+    }
+
+    fn switch(&mut self, index: usize) {
+        if self.current != index {
+            self.set_current(index);
+            self.changed = true;
+        }
+    }
+
+    /// Area with the tabs, including the line separating them from the content.
+    fn strip(&self) -> BBox {
+        let bbox = self.bbox;
+        match self.orient {
+            Orientation::Horizontal => {
+                BBox::new(bbox.start, (bbox.end.0, bbox.start.1 + TAB_HEIGHT))
+            }
+            Orientation::Vertical => {
+                let widest = self.labels.iter().map(|label| FONT_5X7.text_size(label).0);
+                let width = widest.max().unwrap_or(0) + TAB_PADDING * 2;
+                BBox::new(bbox.start, (bbox.start.0 + width, bbox.end.1))
+            }
+        }
+    }
+
+    /// Area the pages are placed in.
+    fn content(&self) -> BBox {
+        let strip = self.strip();
+        match self.orient {
+            Orientation::Horizontal => {
+                BBox::new((self.bbox.start.0, strip.end.1 + 1), self.bbox.end)
+            }
+            Orientation::Vertical => BBox::new((strip.end.0 + 1, self.bbox.start.1), self.bbox.end),
+        }
+    }
+
+    fn tab(&self, index: usize) -> BBox {
+        let strip = self.strip();
+        match self.orient {
+            Orientation::Horizontal => {
+                let width = strip.end.0 - strip.start.0 + 1;
+                let start = strip.start.0 + width * index as i32 / N as i32;
+                let end = strip.start.0 + width * (index as i32 + 1) / N as i32 - 1;
+                BBox::new((start, strip.start.1), (end, strip.end.1 - 1))
+            }
+            Orientation::Vertical => {
+                let top = strip.start.1 + TAB_HEIGHT * index as i32;
+                BBox::new(
+                    (strip.start.0, top),
+                    (strip.end.0 - 1, top + TAB_HEIGHT - 1),
+                )
+            }
+        }
+    }
+
+    fn tab_at(&self, point: Point) -> Option<usize> {
+        (0..N).find(|&index| self.tab(index).contains(point))
+    }
+
+    fn layout(&mut self) {
+        let content = self.content();
+        for index in 0..self.pages.len() {
+            self.pages.set_bbox(index, content);
+        }
+
+        self.dirty = true;
+    }
+}
+
+impl<'a, L: WidgetList, const N: usize> Drawable for TabView<'a, L, N> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let strip = self.strip();
+        Rect::new_filled(strip, self.color.invert()).draw(canvas);
+
+        let line = match self.orient {
+            Orientation::Horizontal => BBox::new((strip.start.0, strip.end.1), strip.end),
+            Orientation::Vertical => BBox::new((strip.end.0, strip.start.1), strip.end),
+        };
+        Line::new(line, self.color).draw(canvas);
+
+        for (index, label) in self.labels.iter().enumerate() {
+            let tab = self.tab(index);
+            let mut color = self.color;
+            if index == self.current {
+                Rect::new_filled(tab, self.color).draw(canvas);
+                color = self.color.invert();
+            }
+
+            Text::new((0, 0), label, color)
+                .align(tab, Alignment::Center, Alignment::Center)
+                .draw(&mut Clip::new(canvas, tab));
+        }
+
+        // Editing is shown with a double outline
+        if self.focus != Focus::None {
+            Rect::new(strip, self.color).draw(canvas);
+        }
+        if self.focus == Focus::Editing {
+            let inner = BBox::new(
+                (strip.start.0 + 1, strip.start.1 + 1),
+                (strip.end.0 - 1, strip.end.1 - 1),
+            );
+            Rect::new(inner, self.color).draw(canvas);
+        }
+
+        if self.current < self.pages.len() {
+            let mut clip = Clip::new(canvas, self.content());
+            self.pages.draw_widget(self.current, &mut clip);
+        }
+    }
+}
+
+impl<'a, L: WidgetList, const N: usize> Widget for TabView<'a, L, N> {
+    fn min_size(&self) -> Size {
+        let strip = self.strip();
+        let (strip_width, strip_height) = (strip.width() as i32 + 1, strip.height() as i32 + 1);
+        let pages = 0..self.pages.len();
+        let width = pages.clone().map(|i| self.pages.min_size(i).width).max();
+        let height = pages.map(|i| self.pages.min_size(i).height).max();
+        let (width, height) = (width.unwrap_or(0), height.unwrap_or(0));
+
+        match self.orient {
+            Orientation::Horizontal => {
+                let tabs = self.labels.iter().map(|label| FONT_5X7.text_size(label).0);
+                let tabs = (tabs.max().unwrap_or(0) + TAB_PADDING * 2) * N as i32;
+                Size::new(width.max(tabs - 1), height + strip_height)
+            }
+            Orientation::Vertical => {
+                Size::new(width + strip_width, height.max(TAB_HEIGHT * N as i32 - 1))
+            }
+        }
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.layout();
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_editable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        if let Some(point) = event.position() {
+            if let Some(index) = self.tab_at(point) {
+                if let Event::TouchDown(_) = event {
+                    self.switch(index);
+                }
+                return true;
+            }
+
+            return self.current < self.pages.len() && self.pages.handle_event(self.current, event);
+        }
+
+        let last = N.saturating_sub(1);
+        match event {
+            Event::KeyPress(Key::Left | Key::Up) | Event::KeyRepeat(Key::Left | Key::Up) => {
+                self.switch(self.current.saturating_sub(1));
+            }
+            Event::KeyPress(Key::Right | Key::Down) | Event::KeyRepeat(Key::Right | Key::Down) => {
+                self.switch((self.current + 1).min(last));
+            }
+            Event::Encoder(steps) => {
+                let index = self.current as isize + *steps as isize;
+                self.switch(index.clamp(0, last as isize) as usize);
+            }
+            _ => {
+                return self.current < self.pages.len()
+                    && self.pages.handle_event(self.current, event);
+            }
+        }
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty || (self.current < self.pages.len() && self.pages.needs_redraw(self.current))
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+        if self.current < self.pages.len() {
+            self.pages.mark_drawn(self.current);
+        }
+    }
+
+    /// Only the current page is drawn, unless the tabs changed.
+    fn redraw<C: PixelDraw>(&mut self, canvas: &mut C) -> bool {
+        if self.dirty {
+            self.draw(canvas);
+            self.mark_drawn();
+            return true;
+        }
+
+        if self.current >= self.pages.len() {
+            return false;
+        }
+
+        let mut clip = Clip::new(canvas, self.content());
+        self.pages.redraw_widget(self.current, &mut clip)
+    }
+}
@@ -0,0 +1,369 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::Size;
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Line, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::Widget;
+
+/// Height of the table row, including padding and separator.
+const ROW_PITCH: i32 = FONT_5X7.height as i32 + 3;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum ColumnWidth {
+    /// Width in pixels
+    Fixed(u32),
+    /// Share of the space, which is left after all fixed columns
+    Proportional(u32),
+}
+
+pub struct Column<'a> {
+    title: &'a str,
+    width: ColumnWidth,
+    align: Alignment,
+}
+
+pub struct Table<'a> {
+    bbox: BBox,
+    columns: &'a [Column<'a>],
+    rows: &'a [&'a [&'a str]],
+    header: bool,
+    borders: bool,
+    selected: Option<usize>,
+    /// First visible row. It only moves when the selection leaves the visible rows.
+    first: usize,
+    color: Color,
+    focus: Focus,
+    dirty: bool,
+}
+
+impl<'a> Column<'a> {
+    pub fn new(title: &'a str, width: ColumnWidth) -> Self {
+        Self {
+            title,
+            width,
+            align: Alignment::Start,
+        }
+    }
+
+    pub fn align(mut self, align: Alignment) -> Self {
+        self.align = align;
+
+        self
+    }
+}
+
+impl<'a> Table<'a> {
+    /// Each row is a slice of cells. Missing cells are left empty.
+    pub fn new(
+        bbox: BBox,
+        columns: &'a [Column<'a>],
+        rows: &'a [&'a [&'a str]],
+        color: Color,
+    ) -> Self {
+        Self {
+            bbox,
+            columns,
+            rows,
+            header: true,
+            borders: true,
+            selected: None,
+            first: 0,
+            color,
+            focus: Focus::None,
+            dirty: true,
+        }
+    }
+
+    /// Show column titles as a first row.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+
+        self
+    }
+
+    /// Draw lines between cells and around the table.
+    pub fn borders(mut self, borders: bool) -> Self {
+        self.borders = borders;
+
+        self
+    }
+
+    /// Highlight the row by inverting it. The table scrolls to keep it visible.
+    pub fn selected(mut self, row: Option<usize>) -> Self {
+        self.selected = row;
+        self.first = self.first_visible_row();
+
+        self
+    }
+
+    /// Index of the first visible row.
+    pub fn scroll(mut self, row: usize) -> Self {
+        self.first = row;
+
+        self
+    }
+
+    pub fn set_selected(&mut self, row: Option<usize>) {
+        if self.selected != row {
+            self.selected = row;
+            self.first = self.first_visible_row();
+            self.dirty = true;
+        }
+    }
+
+    pub fn get_selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    pub fn set_scroll(&mut self, row: usize) {
+        if self.first != row {
+            self.first = row;
+            self.dirty = true;
+        }
+    }
+
+    /// Number of data rows which fit into the table.
+    pub fn visible_rows(&self) -> usize {
+        let slots = ((self.bbox.height() as i32) / ROW_PITCH).max(0) as usize;
+
+        slots.saturating_sub(self.header as usize)
+    }
+
+    /// Index of the first visible row, taking the selection into account.
+    pub fn first_visible_row(&self) -> usize {
+        let visible = self.visible_rows();
+        let mut first = self.first.min(self.rows.len().saturating_sub(visible));
+
+        if let Some(selected) = self.selected {
+            if selected < first {
+                first = selected;
+            } else if visible > 0 && selected >= first + visible {
+                first = selected + 1 - visible;
+            }
+        }
+
+        first
+    }
+
+    /// Horizontal span of the column content, excluding separators.
+    fn column_span(&self, index: usize) -> (i32, i32) {
+        let separators = self.columns.len() as i32 + 1;
+        let available = (self.bbox.width() as i32 + 1 - separators).max(0);
+        let fixed: i32 = self
+            .columns
+            .iter()
+            .map(|column| match column.width {
+                ColumnWidth::Fixed(width) => width as i32,
+                ColumnWidth::Proportional(_) => 0,
+            })
+            .sum();
+        let weights: u32 = self
+            .columns
+            .iter()
+            .map(|column| match column.width {
+                ColumnWidth::Fixed(_) => 0,
+                ColumnWidth::Proportional(weight) => weight,
+            })
+            .sum();
+        let free = (available - fixed).max(0);
+
+        // Share of the free space is computed from the accumulated weight,
+        // so the rounding remainder is distributed and the columns fill the table exactly.
+        let mut x = self.bbox.start.0 + 1;
+        let mut weight_before = 0;
+        for (i, column) in self.columns.iter().enumerate() {
+            let width = match column.width {
+                ColumnWidth::Fixed(width) => width as i32,
+                ColumnWidth::Proportional(weight) if weights > 0 => {
+                    let before = free * weight_before as i32 / weights as i32;
+                    weight_before += weight;
+                    free * weight_before as i32 / weights as i32 - before
+                }
+                ColumnWidth::Proportional(_) => 0,
+            };
+
+            if i == index {
+                return (x, (x + width - 1).min(self.bbox.end.0 - 1));
+            }
+            x += width + 1;
+        }
+
+        (x, x - 1)
+    }
+
+    fn draw_row<C, F>(&self, canvas: &mut C, y: i32, cells: F, color: Color)
+    where
+        C: PixelDraw,
+        F: Fn(usize) -> &'a str,
+    {
+        for (i, column) in self.columns.iter().enumerate() {
+            let (start, end) = self.column_span(i);
+            let cell = BBox::new((start + 1, y + 1), (end - 1, y + ROW_PITCH - 3));
+            let text = cells(i);
+
+            let mut clip = Clip::new(canvas, cell);
+            Text::new(cell.start, text, color)
+                .align(cell, column.align, Alignment::Center)
+                .draw(&mut clip);
+        }
+    }
+}
+
+impl<'a> Drawable for Table<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        let color = self.color;
+        let mut y = bbox.start.1 + 1;
+
+        if self.borders {
+            Rect::new(bbox, color).draw(canvas);
+
+            for i in 1..self.columns.len() {
+                let x = self.column_span(i).0 - 1;
+                Line::new_vertical(BBox::new((x, bbox.start.1), (x, bbox.end.1)), color)
+                    .draw(canvas);
+            }
+        }
+
+        if self.header && bbox.height() as i32 >= ROW_PITCH {
+            self.draw_row(canvas, y, |i| self.columns[i].title, color);
+            y += ROW_PITCH;
+
+            let separator = y - 1;
+            Line::new(
+                BBox::new((bbox.start.0, separator), (bbox.end.0, separator)),
+                color,
+            )
+            .draw(canvas);
+        }
+
+        let first = self.first_visible_row();
+        let rows = self.rows.iter().enumerate().skip(first);
+        for (index, cells) in rows.take(self.visible_rows()) {
+            let mut row_color = color;
+            if self.selected == Some(index) {
+                let highlight =
+                    BBox::new((bbox.start.0 + 1, y), (bbox.end.0 - 1, y + ROW_PITCH - 2));
+                Rect::new_filled(highlight, color).draw(canvas);
+                row_color = color.invert();
+            }
+
+            let cell = |i: usize| cells.get(i).copied().unwrap_or("");
+            self.draw_row(canvas, y, cell, row_color);
+            y += ROW_PITCH;
+
+            if self.borders {
+                let separator = y - 1;
+                Line::new(
+                    BBox::new((bbox.start.0, separator), (bbox.end.0, separator)),
+                    color,
+                )
+                .draw(canvas);
+            }
+        }
+
+        // Focus is shown with a double frame, so it differs from the border
+        if self.focus != Focus::None {
+            Rect::new(bbox, color).draw(canvas);
+            Rect::new(bbox.inset(1), color).draw(canvas);
+        }
+    }
+}
+
+impl<'a> Widget for Table<'a> {
+    fn min_size(&self) -> Size {
+        let separators = self.columns.len() as i32 + 1;
+        let columns: i32 = self
+            .columns
+            .iter()
+            .map(|column| match column.width {
+                ColumnWidth::Fixed(width) => width as i32,
+                ColumnWidth::Proportional(_) => 1,
+            })
+            .sum();
+        let rows = 1 + self.header as i32;
+
+        Size::new(columns + separators - 1, rows * ROW_PITCH)
+    }
+
+    /// Fits all rows and the widest text of every proportional column.
+    fn preferred_size(&self) -> Size {
+        let separators = self.columns.len() as i32 + 1;
+        let columns: i32 = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| match column.width {
+                ColumnWidth::Fixed(width) => width as i32,
+                ColumnWidth::Proportional(_) => {
+                    let title = FONT_5X7.text_size(column.title).0;
+                    let cells = self.rows.iter().filter_map(|row| row.get(i));
+                    let widest = cells.map(|cell| FONT_5X7.text_size(cell).0).max();
+
+                    widest.unwrap_or(0).max(title) + 2
+                }
+            })
+            .sum();
+        let rows = self.rows.len() as i32 + self.header as i32;
+
+        Size::new(columns + separators - 1, rows.max(1) * ROW_PITCH)
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    /// Rows are selected while the table captures input.
+    fn is_editable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    /// Moves the selection with `Up` and `Down` keys or an encoder.
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let last = match self.rows.len() {
+            0 => return false,
+            len => len - 1,
+        };
+
+        let step = match event {
+            Event::KeyPress(Key::Down) | Event::KeyRepeat(Key::Down) => 1,
+            Event::KeyPress(Key::Up) | Event::KeyRepeat(Key::Up) => -1,
+            Event::Encoder(steps) => *steps as isize,
+            _ => return false,
+        };
+
+        let selected = match self.selected {
+            None if step > 0 => 0,
+            None => last,
+            Some(row) => (row as isize + step).clamp(0, last as isize) as usize,
+        };
+
+        self.set_selected(Some(selected));
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
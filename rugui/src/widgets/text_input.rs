@@ -0,0 +1,331 @@
+use crate::coordinates::bounding_box::*;
+use crate::coordinates::cvec::{Point, Size};
+use crate::focus::Focus;
+use crate::framebuffer::*;
+use crate::geometry::{Drawable, Line, Rect};
+use crate::input::{Event, Key};
+use crate::text::{Text, FONT_5X7};
+use crate::widgets::{KeyboardInput, Widget};
+
+/// Space between the outline and the text.
+const PADDING: i32 = 2;
+
+/// Single line text field with a caret, e.g. for a password or a device name.
+///
+/// The text is stored in a provided buffer, so its length is limited by the buffer size.
+/// Only printable ASCII characters can be entered. Characters are typed with a `Keyboard`
+/// and passed by `apply`. While the field captures input, `Left`, `Right` and the encoder
+/// move the caret. A tap moves the caret too.
+///
+/// ```
+/// # use rugui::coordinates::bounding_box::BBox;
+/// # use rugui::framebuffer::Color;
+/// # use rugui::widgets::{KeyboardInput, TextInput};
+/// let mut buffer = [0; 32];
+/// let mut input = TextInput::new(BBox::new((0, 0), (127, 10)), &mut buffer, Color::Black)
+///     .placeholder("Password")
+///     .password(true);
+///
+/// input.apply(KeyboardInput::Char('p'));
+/// input.apply(KeyboardInput::Char('w'));
+/// input.apply(KeyboardInput::Backspace);
+/// assert_eq!(input.text(), "p");
+/// assert!(input.apply(KeyboardInput::Done));
+/// ```
+pub struct TextInput<'a> {
+    bbox: BBox,
+    buffer: &'a mut [u8],
+    len: usize,
+    /// Index of the character the caret is in front of
+    caret: usize,
+    /// Index of the first visible character
+    offset: usize,
+    password: bool,
+    placeholder: &'a str,
+    changed: bool,
+    focus: Focus,
+    color: Color,
+    dirty: bool,
+}
+
+impl<'a> TextInput<'a> {
+    /// The field is empty, the text can't be longer than the `buffer`.
+    pub fn new(bbox: BBox, buffer: &'a mut [u8], color: Color) -> Self {
+        Self {
+            bbox,
+            buffer,
+            len: 0,
+            caret: 0,
+            offset: 0,
+            password: false,
+            placeholder: "",
+            changed: false,
+            focus: Focus::None,
+            color,
+            dirty: true,
+        }
+    }
+
+    /// Show every character as `*`.
+    pub fn password(mut self, password: bool) -> Self {
+        self.password = password;
+
+        self
+    }
+
+    /// Hint shown while the field is empty.
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        core::str::from_utf8(&self.buffer[..self.len]).unwrap_or("")
+    }
+
+    /// Replace the text without reporting a change and put the caret at its end.
+    /// Returns `false` if it doesn't fit or isn't printable ASCII, then the text is kept.
+    pub fn set_text(&mut self, text: &str) -> bool {
+        if text.len() > self.buffer.len()
+            || !text.bytes().all(|c| c.is_ascii_graphic() || c == b' ')
+        {
+            return false;
+        }
+
+        self.buffer[..text.len()].copy_from_slice(text.as_bytes());
+        self.len = text.len();
+        self.offset = 0;
+        self.set_caret(self.len);
+        self.dirty = true;
+
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.set_text("");
+    }
+
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Move the caret in front of the character with the `index`, or to the end.
+    pub fn set_caret(&mut self, index: usize) {
+        let caret = index.min(self.len);
+        if self.caret != caret {
+            self.caret = caret;
+            self.dirty = true;
+        }
+        self.scroll();
+    }
+
+    /// Insert a character at the caret. Returns `false` if the buffer is full
+    /// or the character can't be entered.
+    pub fn insert(&mut self, c: char) -> bool {
+        if self.len == self.buffer.len() || !(c.is_ascii_graphic() || c == ' ') {
+            return false;
+        }
+
+        self.buffer
+            .copy_within(self.caret..self.len, self.caret + 1);
+        self.buffer[self.caret] = c as u8;
+        self.len += 1;
+        self.edited();
+        self.set_caret(self.caret + 1);
+
+        true
+    }
+
+    /// Remove the character in front of the caret.
+    pub fn backspace(&mut self) {
+        if self.caret > 0 {
+            self.set_caret(self.caret - 1);
+            self.delete();
+        }
+    }
+
+    /// Remove the character after the caret.
+    pub fn delete(&mut self) {
+        if self.caret < self.len {
+            self.buffer
+                .copy_within(self.caret + 1..self.len, self.caret);
+            self.len -= 1;
+            self.edited();
+            self.scroll();
+        }
+    }
+
+    /// Edit the text by the input of a `Keyboard`. Returns `true` if the input is done.
+    pub fn apply(&mut self, input: KeyboardInput) -> bool {
+        match input {
+            KeyboardInput::Char(c) => {
+                self.insert(c);
+            }
+            KeyboardInput::Backspace => self.backspace(),
+            KeyboardInput::Done => return true,
+        }
+
+        false
+    }
+
+    /// Returns `true` if the text was edited since the last call.
+    pub fn take_changed(&mut self) -> bool {
+        core::mem::take(&mut self.changed)
+    }
+
+    fn edited(&mut self) {
+        self.changed = true;
+        self.dirty = true;
+    }
+
+    /// Area the text is drawn in.
+    fn inner(&self) -> BBox {
+        BBox::new(
+            (self.bbox.start.0 + PADDING, self.bbox.start.1 + PADDING),
+            (self.bbox.end.0 - PADDING, self.bbox.end.1 - PADDING),
+        )
+    }
+
+    /// Number of characters which fit into the field.
+    fn visible(&self) -> usize {
+        let inner = self.inner();
+        // The first column is left for the caret
+        let width = inner.end.0 - inner.start.0 + FONT_5X7.spacing as i32;
+
+        (width / FONT_5X7.advance() as i32).max(1) as usize
+    }
+
+    /// Scroll the text so that the caret stays visible, with a character after it
+    /// if there is one.
+    fn scroll(&mut self) {
+        let visible = self.visible();
+        let offset = if self.caret < self.offset {
+            self.caret
+        } else if self.caret >= self.offset + visible {
+            self.caret + 1 - visible
+        } else {
+            self.offset.min(self.len.saturating_sub(visible - 1))
+        };
+
+        if self.offset != offset {
+            self.offset = offset;
+            self.dirty = true;
+        }
+    }
+}
+
+impl<'a> Drawable for TextInput<'a> {
+    fn draw<C: PixelDraw>(&self, canvas: &mut C) {
+        let bbox = self.bbox;
+        Rect::new_filled(bbox, self.color.invert()).draw(canvas);
+        Rect::new(bbox, self.color).draw(canvas);
+        if self.focus != Focus::None {
+            let inner = BBox::new(
+                (bbox.start.0 + 1, bbox.start.1 + 1),
+                (bbox.end.0 - 1, bbox.end.1 - 1),
+            );
+            Rect::new(inner, self.color).draw(canvas);
+        }
+
+        let inner = self.inner();
+        let advance = FONT_5X7.advance() as i32;
+        let x = inner.start.0 + 1;
+        let y = inner.start.1 + (inner.height() as i32 + 1 - FONT_5X7.height as i32) / 2;
+        let mut clip = Clip::new(canvas, inner);
+
+        if self.len == 0 && self.focus == Focus::None {
+            Text::new((x, y), self.placeholder, self.color).draw(&mut clip);
+        }
+
+        let end = self.len.min(self.offset + self.visible());
+        let text = &self.text()[self.offset..end];
+        if self.password {
+            for index in 0..text.len() {
+                Text::new((x + index as i32 * advance, y), "*", self.color).draw(&mut clip);
+            }
+        } else {
+            Text::new((x, y), text, self.color).draw(&mut clip);
+        }
+
+        // The caret is in the gap in front of the character
+        let caret = x + (self.caret - self.offset) as i32 * advance - 1;
+        let line = BBox::new((caret, y - 1), (caret, y + FONT_5X7.height as i32));
+        Line::new_vertical(line, self.color).draw(&mut clip);
+    }
+}
+
+impl<'a> Widget for TextInput<'a> {
+    fn min_size(&self) -> Size {
+        let width = FONT_5X7.advance() as i32 * 4;
+
+        Size::new(
+            width + PADDING * 2,
+            FONT_5X7.height as i32 + PADDING * 2 + 1,
+        )
+    }
+
+    fn preferred_size(&self) -> Size {
+        let chars = self.buffer.len().min(20) as i32;
+
+        Size::new(
+            chars * FONT_5X7.advance() as i32 + PADDING * 2,
+            self.min_size().height,
+        )
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bbox
+    }
+
+    fn set_bbox(&mut self, bbox: BBox) {
+        self.bbox = bbox;
+        self.dirty = true;
+        self.scroll();
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn is_editable(&self) -> bool {
+        true
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        self.focus = focus;
+        self.dirty = true;
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::KeyPress(Key::Left) | Event::KeyRepeat(Key::Left) => {
+                self.set_caret(self.caret.saturating_sub(1));
+            }
+            Event::KeyPress(Key::Right) | Event::KeyRepeat(Key::Right) => {
+                self.set_caret(self.caret + 1);
+            }
+            Event::Encoder(steps) => {
+                let caret = self.caret as isize + *steps as isize;
+                self.set_caret(caret.max(0) as usize);
+            }
+            Event::TouchDown(Point { x, .. }) => {
+                // The caret goes to the gap closest to the touch
+                let advance = FONT_5X7.advance() as i32;
+                let column = (x - self.inner().start.0 + advance / 2) / advance;
+                self.set_caret(self.offset + column.max(0) as usize);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.dirty
+    }
+
+    fn mark_drawn(&mut self) {
+        self.dirty = false;
+    }
+}
@@ -0,0 +1,892 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::bounding_box::{Alignment, BBox};
+    use crate::coordinates::cvec::{Point, Size};
+    use crate::focus::Focus;
+    use crate::framebuffer::{Color, Framebuffer, Orientation};
+    use crate::geometry::{Drawable, Rect};
+    use crate::input::{Event, Key};
+    use crate::widgets::{BarChart, LineChart, Scale, Series, Sparkline};
+    use crate::widgets::{Battery, Clock, SignalStrength, StatusBar, StatusIcon};
+    use crate::widgets::{Button, Checkbox, RadioGroup, Toggle};
+    use crate::widgets::{Column, ColumnWidth, ProgressBar, Scroller, Table, Widget, WidgetList};
+    use crate::widgets::{Dialog, MessageBox, Toast};
+    use crate::widgets::{Gauge, GaugeShape, Zone};
+    use crate::widgets::{Keyboard, KeyboardInput, KeyboardLayout, TextInput};
+    use crate::widgets::{Menu, MenuItem, MenuItems, Slider, SpinBox, WIFI_ICON};
+    use crate::widgets::{Pager, TabView};
+
+    fn thumb(scroller: &Scroller) -> ((i32, i32), (i32, i32)) {
+        let thumb = scroller.thumb();
+        (thumb.start, thumb.end)
+    }
+
+    #[test]
+    fn test_scroller_vertical() {
+        let bbox = BBox::new((10, 10), (14, 49));
+        let top = Scroller::new(bbox, 0, 10, 40, Orientation::Vertical, Color::Black);
+        assert_eq!(thumb(&top), ((10, 10), (14, 19)));
+
+        let middle = Scroller::new(bbox, 15, 10, 40, Orientation::Vertical, Color::Black);
+        assert_eq!(thumb(&middle), ((10, 25), (14, 34)));
+
+        let bottom = Scroller::new(bbox, 30, 10, 40, Orientation::Vertical, Color::Black);
+        assert_eq!(thumb(&bottom), ((10, 40), (14, 49)));
+    }
+
+    #[test]
+    fn test_scroller_horizontal() {
+        let bbox = BBox::new((10, 10), (49, 12));
+        let bottom = Scroller::new(bbox, 30, 10, 40, Orientation::Horizontal, Color::Black);
+        assert_eq!(thumb(&bottom), ((40, 10), (49, 12)));
+
+        let mut buffer = [0; 64 * 2];
+        let mut framebuffer = Framebuffer::new(64, 16, &mut buffer).unwrap();
+        bottom.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(10, 11), Color::Black);
+        assert_eq!(framebuffer.get_pixel(10, 10), Color::White);
+        assert_eq!(framebuffer.get_pixel(45, 10), Color::Black);
+        assert_eq!(framebuffer.get_pixel(9, 11), Color::White);
+    }
+
+    #[test]
+    fn test_scroller_clamping() {
+        let bbox = BBox::new((0, 0), (2, 39));
+        let past_end = Scroller::new(bbox, 100, 10, 40, Orientation::Vertical, Color::Black);
+        assert_eq!(thumb(&past_end), ((0, 30), (2, 39)));
+
+        let everything_visible =
+            Scroller::new(bbox, 5, 50, 40, Orientation::Vertical, Color::Black);
+        assert_eq!(thumb(&everything_visible), ((0, 0), (2, 39)));
+
+        let long_content = Scroller::new(bbox, 0, 1, 1000, Orientation::Vertical, Color::Black);
+        assert_eq!(thumb(&long_content), ((0, 0), (2, 2)));
+
+        let huge_content = Scroller::new(
+            bbox,
+            u32::MAX / 2,
+            u32::MAX / 4,
+            u32::MAX,
+            Orientation::Vertical,
+            Color::Black,
+        );
+        assert_eq!(thumb(&huge_content), ((0, 20), (2, 28)));
+    }
+
+    #[test]
+    fn test_table_columns() {
+        let columns = [
+            Column::new("Name", ColumnWidth::Proportional(1)),
+            Column::new("Value", ColumnWidth::Fixed(20)).align(Alignment::End),
+            Column::new("Unit", ColumnWidth::Proportional(2)),
+        ];
+        let rows: [&[&str]; 2] = [&["Temperature", "21", "C"], &["Humidity"]];
+        let table = Table::new(BBox::new((0, 0), (79, 40)), &columns, &rows, Color::Black);
+
+        let mut buffer = [0; 80 * 6];
+        let mut framebuffer = Framebuffer::new(80, 48, &mut buffer).unwrap();
+        table.draw(&mut framebuffer);
+
+        // 76 pixels without separators: 20 fixed, 18 and 38 proportional
+        for x in [0, 19, 40, 79] {
+            assert_eq!(framebuffer.get_pixel(x, 35), Color::Black, "x = {}", x);
+        }
+        assert_eq!(framebuffer.get_pixel(41, 35), Color::White);
+
+        // Without borders only the focus draws a frame
+        let mut table = table.borders(false);
+        let mut buffer = [0; 80 * 6];
+        let mut framebuffer = Framebuffer::new(80, 48, &mut buffer).unwrap();
+        table.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(0, 40), Color::White);
+
+        table.set_focus(Focus::Focused);
+        table.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(0, 40), Color::Black);
+        assert_eq!(framebuffer.get_pixel(1, 39), Color::Black);
+    }
+
+    #[test]
+    fn test_table_scrolling() {
+        let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+        let rows: [&[&str]; 6] = [&["0"], &["1"], &["2"], &["3"], &["4"], &["5"]];
+        let bbox = BBox::new((0, 0), (30, 40));
+
+        let table = Table::new(bbox, &columns, &rows, Color::Black);
+        assert_eq!(table.visible_rows(), 3);
+        assert_eq!(table.first_visible_row(), 0);
+
+        let table = Table::new(bbox, &columns, &rows, Color::Black).scroll(10);
+        assert_eq!(table.first_visible_row(), 3);
+
+        let table = Table::new(bbox, &columns, &rows, Color::Black)
+            .header(false)
+            .selected(Some(5));
+        assert_eq!(table.first_visible_row(), 2);
+
+        let table = Table::new(bbox, &columns, &rows, Color::Black)
+            .scroll(3)
+            .selected(Some(1));
+        assert_eq!(table.first_visible_row(), 1);
+
+        // Moving back up within the visible rows keeps the view in place
+        let mut table = Table::new(bbox, &columns, &rows, Color::Black);
+        for _ in 0..5 {
+            table.handle_event(&Event::KeyPress(Key::Down));
+        }
+        assert_eq!(table.get_selected(), Some(4));
+        assert_eq!(table.first_visible_row(), 2);
+        table.handle_event(&Event::KeyPress(Key::Up));
+        assert_eq!(table.get_selected(), Some(3));
+        assert_eq!(table.first_visible_row(), 2);
+    }
+
+    #[test]
+    fn test_table_too_small() {
+        let columns = [
+            Column::new("A", ColumnWidth::Fixed(50)),
+            Column::new("B", ColumnWidth::Proportional(1)),
+        ];
+        let rows: [&[&str]; 1] = [&["a", "b"]];
+        let table = Table::new(BBox::new((0, 0), (10, 5)), &columns, &rows, Color::Black);
+
+        let mut buffer = [0; 128];
+        let mut framebuffer = Framebuffer::new(16, 8, &mut buffer).unwrap();
+        table.draw(&mut framebuffer);
+        assert_eq!(table.visible_rows(), 0);
+    }
+
+    #[test]
+    fn test_widget_redraw() {
+        let mut buffer = [0; 64 * 2];
+        let mut framebuffer = Framebuffer::new(64, 16, &mut buffer).unwrap();
+        let mut bar = ProgressBar::new(BBox::new((0, 0), (63, 7)), 50, 100, Color::Black);
+
+        assert!(bar.redraw(&mut framebuffer));
+        assert!(!bar.redraw(&mut framebuffer));
+
+        bar.set_value(50);
+        assert!(!bar.needs_redraw());
+        bar.set_value(60);
+        assert!(bar.redraw(&mut framebuffer));
+
+        bar.set_bbox(BBox::new((0, 8), (63, 15)));
+        assert!(bar.needs_redraw());
+    }
+
+    #[test]
+    fn test_widget_list() {
+        let mut buffer = [0; 64 * 2];
+        let mut framebuffer = Framebuffer::new(64, 16, &mut buffer).unwrap();
+        let mut widgets = (
+            ProgressBar::new(BBox::new((0, 0), (63, 3)), 50, 100, Color::Black),
+            Slider::new(BBox::new((0, 4), (63, 7)), 0, 10, 5, Color::Black),
+            Button::new(BBox::new((0, 8), (30, 15)), "OK", Color::Black),
+        );
+
+        assert_eq!(widgets.len(), 3);
+        assert!(!widgets.is_focusable(0));
+        assert!(widgets.is_editable(1));
+        assert_eq!(widgets.position_at((10, 10)), Some(2));
+        assert_eq!(widgets.position_at((40, 10)), None);
+
+        assert!(widgets.redraw_widget(0, &mut framebuffer));
+        assert!(!widgets.needs_redraw(0));
+        assert!(widgets.handle_event(1, &Event::KeyPress(Key::Right)));
+        assert_eq!(widgets.1.value(), 6);
+
+        widgets.set_bbox(2, BBox::new((32, 8), (63, 15)));
+        assert_eq!(widgets.bbox(2), BBox::new((32, 8), (63, 15)));
+        widgets.draw_widget(2, &mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(32, 8), Color::Black);
+
+        let bbox = BBox::new((0, 0), (63, 7));
+        let mut bars = [
+            ProgressBar::new(bbox, 0, 100, Color::Black),
+            ProgressBar::new(bbox, 0, 100, Color::Black),
+        ];
+        bars.set_bbox(1, BBox::new((0, 8), (63, 15)));
+        assert_eq!(bars.position_at((0, 8)), Some(1));
+        assert_eq!(bars[..].len(), 2);
+    }
+
+    #[test]
+    fn test_table_navigation() {
+        let columns = [Column::new("Item", ColumnWidth::Proportional(1))];
+        let rows: [&[&str]; 3] = [&["0"], &["1"], &["2"]];
+        let mut table = Table::new(BBox::new((0, 0), (30, 40)), &columns, &rows, Color::Black);
+        table.mark_drawn();
+
+        assert!(table.handle_event(&Event::KeyPress(Key::Down)));
+        assert_eq!(table.get_selected(), Some(0));
+        assert!(table.needs_redraw());
+
+        table.handle_event(&Event::KeyPress(Key::Up));
+        assert_eq!(table.get_selected(), Some(0));
+
+        table.handle_event(&Event::KeyPress(Key::Down));
+        table.handle_event(&Event::KeyPress(Key::Down));
+        table.handle_event(&Event::KeyPress(Key::Down));
+        assert_eq!(table.get_selected(), Some(2));
+
+        assert!(!table.handle_event(&Event::KeyPress(Key::Enter)));
+        assert_eq!(table.min_size(), Size::new(2, 20));
+    }
+
+    #[test]
+    fn test_menu_scrolling() {
+        let items = ["Zero", "One", "Two", "Three", "Four", "Five"];
+        let mut menu = Menu::new(
+            BBox::new((0, 0), (63, 26)),
+            MenuItems::Slice(&items),
+            Color::Black,
+        );
+        assert_eq!(menu.visible_items(), 3);
+
+        for _ in 0..4 {
+            menu.handle_event(&Event::KeyPress(Key::Down));
+        }
+        assert_eq!(menu.selected(), 4);
+        menu.handle_event(&Event::Encoder(5));
+        assert_eq!(menu.selected(), 5);
+
+        let mut buffer = [0; 64 * 4];
+        let mut framebuffer = Framebuffer::new(64, 32, &mut buffer).unwrap();
+        menu.draw(&mut framebuffer);
+
+        // Selected item is the last visible one and it's inverted
+        assert_eq!(framebuffer.get_pixel(0, 18), Color::Black);
+        assert_eq!(framebuffer.get_pixel(0, 17), Color::White);
+        // Scroller thumb is at the bottom
+        assert_eq!(framebuffer.get_pixel(63, 26), Color::Black);
+        assert_eq!(framebuffer.get_pixel(63, 0), Color::White);
+
+        menu.select(0);
+        assert!(menu.handle_event(&Event::KeyPress(Key::Enter)));
+        assert_eq!(menu.take_activated(), Some(0));
+        assert_eq!(menu.take_activated(), None);
+
+        // A white menu is drawn on black
+        let menu = Menu::new(
+            BBox::new((0, 0), (63, 26)),
+            MenuItems::Slice(&items),
+            Color::White,
+        );
+        menu.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(0, 17), Color::Black);
+        assert_eq!(framebuffer.get_pixel(0, 0), Color::White);
+    }
+
+    #[test]
+    fn test_menu_callback() {
+        const LABELS: [&str; 3] = ["A", "B", "C"];
+        let label = |index: usize| LABELS[index % 3];
+        let mut menu = Menu::new(
+            BBox::new((0, 0), (63, 26)),
+            MenuItems::Fn(100, &label),
+            Color::Black,
+        );
+
+        menu.handle_event(&Event::KeyPress(Key::Up));
+        assert_eq!(menu.selected(), 0);
+        menu.handle_event(&Event::Encoder(127));
+        assert_eq!(menu.selected(), 99);
+        assert!(!menu.handle_event(&Event::KeyPress(Key::Back)));
+    }
+
+    #[test]
+    fn test_menu_tree() {
+        const DISPLAY: [MenuItem<'static>; 2] = [MenuItem::new("Contrast"), MenuItem::new("Flip")];
+        const ROOT: [MenuItem<'static>; 3] = [
+            MenuItem::new("Info"),
+            MenuItem::submenu("Display", &DISPLAY),
+            MenuItem::new("Reset"),
+        ];
+        let mut menu = Menu::new(
+            BBox::new((0, 0), (63, 63)),
+            MenuItems::Tree(&ROOT),
+            Color::Black,
+        );
+
+        menu.handle_event(&Event::KeyPress(Key::Down));
+        menu.handle_event(&Event::KeyPress(Key::Enter));
+        assert_eq!(menu.take_activated(), None);
+        assert_eq!(menu.path(), &[1, 0]);
+        assert_eq!(menu.len(), 2);
+
+        menu.handle_event(&Event::KeyPress(Key::Down));
+        menu.handle_event(&Event::KeyPress(Key::Down));
+        assert_eq!(menu.path(), &[1, 1]);
+
+        assert!(menu.handle_event(&Event::KeyPress(Key::Back)));
+        assert_eq!(menu.path(), &[1]);
+        assert_eq!(menu.len(), 3);
+    }
+
+    #[test]
+    fn test_menu_touch() {
+        let items = ["Zero", "One", "Two"];
+        let mut menu = Menu::new(
+            BBox::new((0, 0), (63, 63)),
+            MenuItems::Slice(&items),
+            Color::Black,
+        );
+
+        assert!(menu.handle_event(&Event::TouchDown(Point::new(5, 20))));
+        assert_eq!(menu.selected(), 2);
+        menu.handle_event(&Event::TouchUp(Point::new(5, 20)));
+        assert_eq!(menu.take_activated(), Some(2));
+        assert!(!menu.handle_event(&Event::TouchDown(Point::new(5, 40))));
+    }
+
+    #[test]
+    fn test_button() {
+        let mut button = Button::new(BBox::new((0, 0), (30, 12)), "OK", Color::Black);
+        assert!(!button.handle_event(&Event::KeyRelease(Key::Enter)));
+
+        button.handle_event(&Event::KeyPress(Key::Enter));
+        assert!(button.is_pressed());
+        assert!(!button.take_clicked());
+
+        button.handle_event(&Event::KeyRelease(Key::Enter));
+        assert!(!button.is_pressed());
+        assert!(button.take_clicked());
+        assert!(!button.take_clicked());
+
+        let mut buffer = [0; 32 * 2];
+        let mut framebuffer = Framebuffer::new(32, 16, &mut buffer).unwrap();
+        button.handle_event(&Event::TouchDown(Point::new(5, 5)));
+        button.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(2, 2), Color::Black);
+    }
+
+    #[test]
+    fn test_checkbox_and_toggle() {
+        let mut checkbox = Checkbox::new(BBox::new((0, 0), (50, 8)), "Wi-Fi", false, Color::Black);
+        assert_eq!(checkbox.take_changed(), None);
+        assert!(checkbox.handle_event(&Event::KeyPress(Key::Enter)));
+        assert_eq!(checkbox.take_changed(), Some(true));
+        checkbox.set_checked(false);
+        assert_eq!(checkbox.take_changed(), None);
+        assert!(!checkbox.is_checked());
+
+        let mut toggle = Toggle::new(BBox::new((0, 0), (50, 8)), "Wi-Fi", true, Color::Black);
+        toggle.handle_event(&Event::TouchDown(Point::new(3, 3)));
+        assert_eq!(toggle.take_changed(), Some(false));
+        assert!(!toggle.is_on());
+        assert_eq!(toggle.min_size().width, checkbox.min_size().width + 9);
+    }
+
+    #[test]
+    fn test_radio_group() {
+        let options = ["Low", "Medium", "High"];
+        let mut radio = RadioGroup::new(BBox::new((0, 0), (50, 29)), &options, 0, Color::Black);
+
+        assert!(!radio.handle_event(&Event::KeyPress(Key::Enter)));
+        assert_eq!(radio.take_changed(), None);
+
+        radio.handle_event(&Event::KeyPress(Key::Down));
+        radio.handle_event(&Event::Encoder(1));
+        assert_eq!(radio.selected(), 0);
+        assert_eq!(radio.take_changed(), None);
+        assert!(!radio.handle_event(&Event::KeyPress(Key::Enter)));
+        assert_eq!(radio.selected(), 2);
+        assert_eq!(radio.take_changed(), Some(2));
+
+        // Leaving without `Enter` keeps the selection
+        radio.set_focus(Focus::Editing);
+        radio.handle_event(&Event::KeyPress(Key::Up));
+        assert!(!radio.handle_event(&Event::KeyPress(Key::Back)));
+        radio.set_focus(Focus::Focused);
+        assert_eq!(radio.selected(), 2);
+        assert_eq!(radio.take_changed(), None);
+
+        radio.handle_event(&Event::TouchDown(Point::new(2, 12)));
+        assert_eq!(radio.take_changed(), Some(1));
+        assert!(!radio.handle_event(&Event::TouchDown(Point::new(2, 35))));
+    }
+
+    #[test]
+    fn test_slider() {
+        let bbox = BBox::new((0, 0), (21, 6));
+        let mut slider = Slider::new(bbox, -10, 10, 0, Color::Black).step(2);
+
+        slider.handle_event(&Event::KeyPress(Key::Left));
+        assert_eq!(slider.take_changed(), Some(-2));
+        slider.handle_event(&Event::Encoder(-10));
+        assert_eq!(slider.take_changed(), Some(-10));
+        assert!(!slider.handle_event(&Event::KeyPress(Key::Enter)));
+
+        slider.handle_event(&Event::TouchDown(Point::new(16, 3)));
+        assert_eq!(slider.take_changed(), Some(6));
+        slider.handle_event(&Event::TouchMove(Point::new(30, 3)));
+        assert_eq!(slider.value(), 10);
+
+        let mut buffer = [0; 32];
+        let mut framebuffer = Framebuffer::new(32, 8, &mut buffer).unwrap();
+        slider.set_value(0);
+        slider.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(10, 3), Color::Black);
+        assert_eq!(framebuffer.get_pixel(11, 3), Color::White);
+        assert_eq!(framebuffer.get_pixel(10, 0), Color::White);
+
+        let vertical = Slider::new(bbox, 0, 4, 4, Color::Black).orientation(Orientation::Vertical);
+        assert_eq!(vertical.min_size(), Size::new(2, 6));
+    }
+
+    #[test]
+    fn test_spin_box() {
+        let bbox = BBox::new((0, 0), (63, 10));
+        let mut spin = SpinBox::new(bbox, 52, -200, 200, Color::Black).decimals(1);
+        let text = |spin: &SpinBox<'_>| {
+            let mut buffer = [0; 128];
+            let mut framebuffer = Framebuffer::new(64, 11, &mut buffer[..]).unwrap();
+            spin.draw(&mut framebuffer);
+            framebuffer.get_pixel(2 + 6 * 4 + 2, 6)
+        };
+        assert_eq!(text(&spin), Color::Black);
+
+        spin.set_focus(Focus::Editing);
+        spin.handle_event(&Event::KeyPress(Key::Down));
+        assert_eq!(spin.take_changed(), Some(-48));
+
+        spin.handle_event(&Event::KeyPress(Key::Enter));
+        spin.handle_event(&Event::Encoder(-3));
+        assert_eq!(spin.value(), -78);
+        assert!(spin.handle_event(&Event::KeyPress(Key::Enter)));
+        assert!(!spin.handle_event(&Event::KeyPress(Key::Enter)));
+
+        spin.handle_event(&Event::KeyPress(Key::Up));
+        assert_eq!(spin.value(), -77);
+        spin.handle_event(&Event::KeyPress(Key::Left));
+        spin.handle_event(&Event::KeyPress(Key::Left));
+        spin.handle_event(&Event::Encoder(-5));
+        assert_eq!(spin.value(), -200);
+
+        spin.handle_event(&Event::KeyPress(Key::Back));
+        assert_eq!(spin.take_changed(), Some(52));
+    }
+
+    #[test]
+    fn test_progress_bar() {
+        let mut buffer = [0; 64 * 2];
+        let mut framebuffer = Framebuffer::new(64, 16, &mut buffer).unwrap();
+        let bbox = BBox::new((0, 0), (21, 10));
+
+        let bar = ProgressBar::new(bbox, 1, 2, Color::Black);
+        assert_eq!(bar.percent(), 50);
+        let huge = ProgressBar::new(bbox, u32::MAX / 4 * 3, u32::MAX, Color::Black);
+        assert_eq!(huge.percent(), 74);
+        assert_eq!(ProgressBar::new(bbox, 0, 0, Color::Black).percent(), 100);
+        bar.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(10, 5), Color::Black);
+        assert_eq!(framebuffer.get_pixel(11, 5), Color::White);
+
+        // The label is inverted over the filled part
+        bar.label(true).draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(6, 7), Color::White);
+        assert_eq!(framebuffer.get_pixel(12, 7), Color::Black);
+
+        let bar = ProgressBar::new(bbox, 3, 4, Color::Black).segments(4);
+        bar.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(5, 5), Color::White);
+        assert_eq!(framebuffer.get_pixel(14, 5), Color::Black);
+        assert_eq!(framebuffer.get_pixel(15, 5), Color::White);
+        assert_eq!(framebuffer.get_pixel(16, 5), Color::White);
+
+        let bar = ProgressBar::new(bbox, 1, 4, Color::Black).orientation(Orientation::Vertical);
+        bar.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(5, 9), Color::Black);
+        assert_eq!(framebuffer.get_pixel(5, 7), Color::White);
+
+        let mut bar = ProgressBar::new(bbox, 0, 0, Color::Black).indeterminate(true);
+        // The block of 5 pixels returns from the end after 15 ticks
+        for _ in 0..17 {
+            bar.tick();
+        }
+        bar.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(13, 5), Color::White);
+        assert_eq!(framebuffer.get_pixel(14, 5), Color::Black);
+        assert_eq!(framebuffer.get_pixel(18, 5), Color::Black);
+        assert_eq!(framebuffer.get_pixel(19, 5), Color::White);
+    }
+
+    #[test]
+    fn test_chart_scale() {
+        let scale = Scale::new(3, 47, 5);
+        assert_eq!((scale.min, scale.max, scale.step), (0, 50, 10));
+        assert_eq!(scale.ticks().count(), 6);
+        assert_eq!(scale.map(25, 40, 0), 20);
+
+        let scale = Scale::new(-7, -7, 4);
+        assert_eq!((scale.min, scale.max, scale.step), (-7, -6, 1));
+
+        let ring = [4, 5, 1, 2, 3];
+        let series = Series::Ring(&ring, 2);
+        assert!(series.iter().eq([1, 2, 3, 4, 5]));
+        assert_eq!(series.range(), Some((1, 5)));
+    }
+
+    #[test]
+    fn test_charts() {
+        let mut buffer = [0; 64 * 4];
+        let mut framebuffer = Framebuffer::new(64, 32, &mut buffer).unwrap();
+        let samples = [0, 10, -10];
+
+        let chart = LineChart::new(
+            BBox::new((0, 0), (63, 31)),
+            Series::Slice(&samples),
+            Color::Black,
+        )
+        .labels(false);
+        assert_eq!(
+            chart.scale(),
+            Scale {
+                min: -10,
+                max: 10,
+                step: 5
+            }
+        );
+        chart.draw(&mut framebuffer);
+        // Axes with ticks, the line starts in the middle on the left and ends in the bottom right
+        assert_eq!(framebuffer.get_pixel(2, 31), Color::Black);
+        assert_eq!(framebuffer.get_pixel(0, 15), Color::Black);
+        assert_eq!(framebuffer.get_pixel(3, 15), Color::Black);
+        assert_eq!(framebuffer.get_pixel(63, 30), Color::Black);
+
+        let chart = BarChart::new(
+            BBox::new((0, 0), (63, 31)),
+            Series::Slice(&samples),
+            Color::Black,
+        )
+        .labels(false);
+        chart.draw(&mut framebuffer);
+        // Bars grow from zero in the middle
+        assert_eq!(framebuffer.get_pixel(10, 15), Color::Black);
+        assert_eq!(framebuffer.get_pixel(10, 14), Color::White);
+        assert_eq!(framebuffer.get_pixel(30, 2), Color::Black);
+        assert_eq!(framebuffer.get_pixel(50, 28), Color::Black);
+        assert_eq!(framebuffer.get_pixel(50, 14), Color::White);
+
+        let line = Sparkline::new(
+            BBox::new((0, 0), (8, 4)),
+            Series::Slice(&[0, 4, 2]),
+            Color::Black,
+        );
+        line.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(0, 4), Color::Black);
+        assert_eq!(framebuffer.get_pixel(4, 0), Color::Black);
+        assert_eq!(framebuffer.get_pixel(8, 2), Color::Black);
+
+        // Labels of a chart which is too low stay within its bounding box
+        let mut buffer = [0; 64 * 4];
+        let mut framebuffer = Framebuffer::new(64, 32, &mut buffer).unwrap();
+        let bbox = BBox::new((0, 12), (63, 17));
+        LineChart::new(bbox, Series::Slice(&samples), Color::Black).draw(&mut framebuffer);
+        for y in (0..12).chain(18..32) {
+            for x in 0..64 {
+                assert_eq!(framebuffer.get_pixel(x, y), Color::White, "({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gauge() {
+        let mut buffer = [0; 64 * 4];
+        let mut framebuffer = Framebuffer::new(64, 32, &mut buffer).unwrap();
+        let bbox = BBox::new((0, 0), (40, 20));
+        let zones = [Zone {
+            from: 0,
+            to: 10,
+            color: Color::Black,
+        }];
+
+        // The needle points straight up in the middle of a semicircle
+        let mut gauge = Gauge::new(bbox, -100, 100, 0, Color::Black)
+            .shape(GaugeShape::Semicircle)
+            .zones(&zones);
+        gauge.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(20, 10), Color::Black);
+        assert_eq!(framebuffer.get_pixel(10, 10), Color::White);
+        // The zone goes from the top to the right
+        assert_eq!(framebuffer.get_pixel(22, 3), Color::Black);
+        assert_eq!(framebuffer.get_pixel(18, 3), Color::White);
+
+        // The maximum is on the right
+        gauge.set_value(1000);
+        assert_eq!(gauge.value(), 100);
+        gauge.draw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(30, 20), Color::Black);
+        assert_eq!(framebuffer.get_pixel(20, 10), Color::White);
+    }
+
+    #[test]
+    fn test_status_bar() {
+        let mut buffer = [0; 64 * 2];
+        let mut framebuffer = Framebuffer::new(64, 16, &mut buffer).unwrap();
+        let zero = BBox::new((0, 0), (0, 0));
+
+        let battery = Battery::new(zero, 30, Color::Black);
+        assert_eq!(battery.level(), 2);
+        assert_eq!(Battery::new(zero, 1, Color::Black).level(), 1);
+
+        let mut bar = StatusBar::new(
+            BBox::new((0, 0), (63, 8)),
+            (
+                SignalStrength::new(zero, 2, Color::Black),
+                StatusIcon::new(zero, WIFI_ICON, Color::Black),
+                Clock::new(zero, Color::Black),
+            ),
+            Color::Black,
+        );
+        let boxes = [0, 1, 2].map(|i| (bar.items().bbox(i).start, bar.items().bbox(i).end));
+        assert_eq!(boxes[2], ((35, 1), (63, 7)));
+        assert_eq!(boxes[1], ((25, 1), (31, 6)));
+        assert_eq!(boxes[0], ((11, 1), (21, 7)));
+        assert!(bar.redraw(&mut framebuffer));
+
+        // Bars above the level only have a stub at the bottom
+        assert_eq!(framebuffer.get_pixel(11, 6), Color::Black);
+        assert_eq!(framebuffer.get_pixel(14, 5), Color::Black);
+        assert_eq!(framebuffer.get_pixel(17, 6), Color::White);
+        assert_eq!(framebuffer.get_pixel(17, 7), Color::Black);
+
+        // Only the changed item is drawn again
+        Rect::new_filled(BBox::new((0, 0), (63, 15)), Color::White).draw(&mut framebuffer);
+        bar.items_mut().2.set_time(25, 7, 30);
+        assert_eq!(bar.items().2.time(), (1, 7, 30));
+        assert!(bar.redraw(&mut framebuffer));
+        assert!(!bar.redraw(&mut framebuffer));
+        assert_eq!(framebuffer.get_pixel(14, 5), Color::White);
+        assert_eq!(framebuffer.get_pixel(36, 1), Color::Black);
+    }
+
+    #[test]
+    fn test_dialog() {
+        let bbox = BBox::new((0, 0), (99, 39));
+        let mut dialog =
+            Dialog::new(bbox, "Title", "Text", ["A", "B", "C"], Color::Black).cancel(2);
+        assert_eq!(dialog.focused_button(), Some(0));
+        assert_eq!(dialog.area().end, (101, 41));
+
+        // Everything is consumed, navigation wraps around the buttons
+        let press = |dialog: &mut Dialog<'_, 3>, key| {
+            assert!(dialog.handle_event(&Event::KeyPress(key)));
+            assert!(dialog.handle_event(&Event::KeyRelease(key)));
+        };
+        press(&mut dialog, Key::Left);
+        assert_eq!(dialog.focused_button(), Some(2));
+        assert!(dialog.handle_event(&Event::Encoder(2)));
+        assert_eq!(dialog.focused_button(), Some(1));
+        assert_eq!(dialog.take_result(), None);
+        press(&mut dialog, Key::Enter);
+        assert_eq!(dialog.take_result(), Some(1));
+        assert_eq!(dialog.take_result(), None);
+
+        press(&mut dialog, Key::Back);
+        assert_eq!(dialog.take_result(), Some(2));
+
+        let mut message = MessageBox::new(bbox, "", "Sure?", Color::Black);
+        assert!(message.handle_event(&Event::KeyPress(Key::Back)));
+        assert_eq!(message.take_answer(), Some(false));
+    }
+
+    #[test]
+    fn test_toast() {
+        let mut buffer = [0; 64 * 4];
+        let mut framebuffer = Framebuffer::new(64, 32, &mut buffer).unwrap();
+        let mut toast = Toast::new(BBox::new((0, 0), (63, 31)), Color::Black);
+
+        // A hidden toast draws nothing
+        toast.redraw(&mut framebuffer);
+        assert!(framebuffer.as_bytes().iter().all(|&byte| byte == 0));
+
+        toast.show("Hi", 100);
+        let bbox = toast.bbox();
+        assert_eq!((bbox.start, bbox.end), ((22, 13), (38, 25)));
+        assert!(toast.redraw(&mut framebuffer));
+        assert_eq!(framebuffer.get_pixel(22, 13), Color::Black);
+        assert_eq!(framebuffer.get_pixel(23, 14), Color::White);
+        assert_eq!(framebuffer.get_pixel(40, 27), Color::Black);
+
+        assert!(!toast.update(2099));
+        assert!(toast.update(2100));
+        assert!(!toast.is_visible());
+        assert!(toast.needs_redraw());
+        assert!(!toast.update(2200));
+    }
+
+    #[test]
+    fn test_text_input() {
+        let mut buffer = [0; 8];
+        let bbox = BBox::new((0, 0), (31, 10));
+        let mut input = TextInput::new(bbox, &mut buffer, Color::Black);
+
+        assert!(input.set_text("abc"));
+        assert!(!input.set_text("tab\t"));
+        assert!(!input.take_changed());
+        assert_eq!(input.caret(), 3);
+
+        // Typing goes to the caret
+        input.set_caret(1);
+        assert!(input.insert('X'));
+        assert_eq!(input.text(), "aXbc");
+        input.backspace();
+        input.delete();
+        assert_eq!(input.text(), "ac");
+        assert_eq!(input.caret(), 1);
+        assert!(input.take_changed());
+        assert!(!input.insert('é'));
+
+        for c in "defghi".chars() {
+            input.insert(c);
+        }
+        assert!(!input.insert('j'));
+        assert_eq!(input.text(), "adefghic");
+
+        input.set_focus(Focus::Editing);
+        assert!(input.handle_event(&Event::Encoder(-10)));
+        assert_eq!(input.caret(), 0);
+        assert!(input.handle_event(&Event::KeyPress(Key::Right)));
+        assert_eq!(input.caret(), 1);
+        assert!(!input.handle_event(&Event::KeyPress(Key::Enter)));
+        assert!(input.handle_event(&Event::TouchDown(Point::new(15, 5))));
+        assert_eq!(input.caret(), 2);
+    }
+
+    #[test]
+    fn test_keyboard() {
+        let mut keyboard = Keyboard::new(BBox::new((0, 0), (99, 39)), Color::Black);
+        keyboard.set_focus(Focus::Editing);
+        let press = |keyboard: &mut Keyboard, key| {
+            assert!(keyboard.handle_event(&Event::KeyPress(key)));
+            keyboard.take_input()
+        };
+
+        // Up wraps to the bottom row, left wraps within the row
+        assert_eq!(press(&mut keyboard, Key::Up), None);
+        assert_eq!(press(&mut keyboard, Key::Left), None);
+        assert_eq!(press(&mut keyboard, Key::Enter), Some(KeyboardInput::Done));
+        assert_eq!(press(&mut keyboard, Key::Left), None);
+        assert_eq!(
+            press(&mut keyboard, Key::Enter),
+            Some(KeyboardInput::Backspace)
+        );
+
+        // Shift switches to the upper case
+        assert!(keyboard.handle_event(&Event::Encoder(-3)));
+        assert_eq!(press(&mut keyboard, Key::Enter), None);
+        assert_eq!(keyboard.current_layout(), KeyboardLayout::Uppercase);
+        assert_eq!(press(&mut keyboard, Key::Up), None);
+        assert_eq!(
+            press(&mut keyboard, Key::Enter),
+            Some(KeyboardInput::Char('Z'))
+        );
+
+        // Keys are typed on release of a touch over them
+        keyboard.set_layout(KeyboardLayout::Digits);
+        assert!(keyboard.handle_event(&Event::TouchDown(Point::new(25, 5))));
+        assert_eq!(keyboard.take_input(), None);
+        assert!(keyboard.handle_event(&Event::TouchUp(Point::new(25, 5))));
+        assert_eq!(keyboard.take_input(), Some(KeyboardInput::Char('3')));
+        assert!(keyboard.handle_event(&Event::TouchDown(Point::new(25, 5))));
+        assert!(keyboard.handle_event(&Event::TouchUp(Point::new(45, 5))));
+        assert_eq!(keyboard.take_input(), None);
+        assert!(!keyboard.handle_event(&Event::KeyPress(Key::Back)));
+    }
+
+    fn pages<const N: usize>() -> [ProgressBar; N] {
+        let zero = BBox::new((0, 0), (0, 0));
+        core::array::from_fn(|index| ProgressBar::new(zero, index as u32, 100, Color::Black))
+    }
+
+    #[test]
+    fn test_tab_view() {
+        let bbox = BBox::new((0, 0), (59, 39));
+        let mut tabs = TabView::new(bbox, ["A", "B", "C"], pages::<3>(), Color::Black);
+        let content = tabs.pages().bbox(0);
+        assert_eq!((content.start, content.end), ((0, 12), (59, 39)));
+
+        tabs.handle_event(&Event::TouchDown(Point::new(45, 5)));
+        assert_eq!(tabs.take_changed(), Some(2));
+        assert_eq!(tabs.pages()[2].value(), 2);
+        assert!(tabs.handle_event(&Event::KeyPress(Key::Right)));
+        assert_eq!(tabs.take_changed(), None);
+        assert!(tabs.handle_event(&Event::Encoder(-5)));
+        assert_eq!(tabs.take_changed(), Some(0));
+
+        let tabs = TabView::new(bbox, ["One", "Two"], pages::<2>(), Color::Black)
+            .orientation(Orientation::Vertical);
+        let content = tabs.pages().bbox(0);
+        assert_eq!((content.start, content.end), ((24, 0), (59, 39)));
+
+        // Pages of different types
+        let zero = BBox::new((0, 0), (0, 0));
+        let pages = (
+            Slider::new(zero, 0, 10, 5, Color::Black),
+            Button::new(zero, "OK", Color::Black),
+        );
+        let mut tabs = TabView::new(bbox, ["Level", "Go"], pages, Color::Black);
+        tabs.handle_event(&Event::TouchDown(Point::new(45, 5)));
+        assert!(tabs.handle_event(&Event::TouchDown(Point::new(20, 20))));
+        assert!(tabs.pages().1.is_pressed());
+        assert_eq!(tabs.pages().0.value(), 5);
+    }
+
+    #[test]
+    fn test_pager() {
+        let mut buffer = [0; 64 * 4];
+        let mut framebuffer = Framebuffer::new(64, 32, &mut buffer).unwrap();
+        let mut pager = Pager::new(BBox::new((0, 0), (63, 31)), pages::<3>(), Color::Black);
+        assert_eq!(pager.pages()[0].bbox().end, (63, 24));
+
+        // Short moves are taps, long ones are swipes
+        pager.handle_event(&Event::TouchDown(Point::new(30, 10)));
+        pager.handle_event(&Event::TouchUp(Point::new(20, 10)));
+        assert_eq!(pager.take_changed(), None);
+        pager.handle_event(&Event::TouchDown(Point::new(30, 10)));
+        pager.handle_event(&Event::TouchUp(Point::new(10, 10)));
+        assert_eq!(pager.take_changed(), Some(1));
+        pager.handle_event(&Event::TouchUp(Point::new(60, 10)));
+        assert_eq!(pager.take_changed(), None);
+
+        // Taps on the dots move towards them
+        pager.handle_event(&Event::TouchDown(Point::new(50, 28)));
+        assert_eq!(pager.take_changed(), Some(2));
+        pager.next();
+        assert_eq!(pager.current(), 2);
+        pager.prev();
+        assert_eq!(pager.take_changed(), Some(1));
+
+        pager.redraw(&mut framebuffer);
+        assert_eq!(framebuffer.get_pixel(31, 28), Color::Black);
+        assert_eq!(framebuffer.get_pixel(23, 28), Color::White);
+        assert_eq!(framebuffer.get_pixel(22, 28), Color::Black);
+    }
+
+    #[test]
+    fn test_pager_buttons() {
+        let zero = BBox::new((0, 0), (0, 0));
+        let buttons = [
+            Button::new(zero, "A", Color::Black),
+            Button::new(zero, "B", Color::Black),
+        ];
+        let mut pager = Pager::new(BBox::new((0, 0), (63, 31)), buttons, Color::Black);
+
+        // A swipe which starts on the button doesn't press it
+        assert!(pager.handle_event(&Event::TouchDown(Point::new(40, 10))));
+        assert!(pager.handle_event(&Event::TouchMove(Point::new(30, 10))));
+        assert!(!pager.pages()[0].is_pressed());
+        pager.handle_event(&Event::TouchUp(Point::new(10, 10)));
+        assert_eq!(pager.take_changed(), Some(1));
+        pager.prev();
+        assert!(!pager.pages()[0].is_pressed());
+        assert!(!pager.pages_mut()[0].take_clicked());
+
+        // A tap clicks it
+        pager.handle_event(&Event::TouchDown(Point::new(40, 10)));
+        assert!(pager.handle_event(&Event::TouchUp(Point::new(42, 10))));
+        assert!(!pager.pages()[0].is_pressed());
+        assert!(pager.pages_mut()[0].take_clicked());
+    }
+}